@@ -0,0 +1,163 @@
+use iced::widget::pane_grid::{self, PaneGrid};
+use iced::widget::{container, Container, Text};
+use iced::{Element, Length, Padding, Task};
+use iced_reorderable::index::move_item;
+use iced_reorderable::{Column, ItemIndex, ItemStatus, SlotIndex};
+
+pub fn main() -> iced::Result {
+    iced::application("Reorderable panes", Panes::update, Panes::view)
+        .window_size((560.0, 360.0))
+        .run()
+}
+
+struct Panes {
+    panes: pane_grid::State<List>,
+}
+
+struct List {
+    title: String,
+    items: Vec<String>,
+}
+
+impl Default for Panes {
+    fn default() -> Self {
+        let (mut panes, first) = pane_grid::State::new(List {
+            title: "Fruits".to_string(),
+            items: vec![
+                "Apple".to_string(),
+                "Banana".to_string(),
+                "Cherry".to_string(),
+            ],
+        });
+        let _ = panes.split(
+            pane_grid::Axis::Vertical,
+            first,
+            List {
+                title: "Vegetables".to_string(),
+                items: vec![
+                    "Tomato".to_string(),
+                    "Lettuce".to_string(),
+                    "Carrot".to_string(),
+                ],
+            },
+        );
+
+        Self { panes }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Drop(pane_grid::Pane, usize, usize),
+    PaneDragged(pane_grid::DragEvent),
+    PaneResized(pane_grid::ResizeEvent),
+}
+
+impl Panes {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Drop(pane, key, loc) => {
+                if let Some(list) = self.panes.get_mut(pane) {
+                    move_item(&mut list.items, ItemIndex(key), SlotIndex(loc));
+                }
+            }
+            Message::PaneDragged(pane_grid::DragEvent::Dropped { pane, target }) => {
+                self.panes.drop(pane, target);
+            }
+            Message::PaneDragged(_) => {}
+            Message::PaneResized(pane_grid::ResizeEvent { split, ratio }) => {
+                self.panes.resize(split, ratio);
+            }
+        }
+
+        Task::none()
+    }
+
+    fn view(&self) -> Element<Message> {
+        const ITEM_PADDING: Padding = Padding {
+            top: 5.0,
+            right: 8.0,
+            bottom: 5.0,
+            left: 8.0,
+        };
+
+        // The columns capture the presses grabbing their items, so the pane grid only
+        // picks a pane when its title bar is pressed
+        PaneGrid::new(&self.panes, |pane, list, _is_maximized| {
+            let items = Column::with_children_status(0..list.items.len(), |index, status| {
+                let item_style = match status {
+                    ItemStatus::Dragged => style::item_dragged,
+                    _ => style::item_idle,
+                };
+                Container::new(Text::new(&list.items[index]))
+                    .padding(ITEM_PADDING)
+                    .width(Length::Fill)
+                    .style(item_style)
+                    .into()
+            })
+            .spacing(10)
+            .width(Length::Fill)
+            .on_drop(move |key, index| Message::Drop(pane, key, index));
+
+            let title_bar = pane_grid::TitleBar::new(Text::new(&list.title))
+                .padding(8)
+                .style(style::title_bar);
+
+            pane_grid::Content::new(container(items).padding(12))
+                .title_bar(title_bar)
+                .style(style::pane)
+        })
+        .spacing(10)
+        .on_drag(Message::PaneDragged)
+        .on_resize(10, Message::PaneResized)
+        .into()
+    }
+}
+
+mod style {
+    use iced::widget::container;
+    use iced::Theme;
+
+    pub fn item_idle(theme: &Theme) -> container::Style {
+        container::Style {
+            border: iced::Border {
+                color: theme.extended_palette().secondary.weak.color.into(),
+                width: 1.0,
+                radius: 5.0.into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn item_dragged(theme: &Theme) -> container::Style {
+        container::Style {
+            border: iced::Border {
+                color: theme.extended_palette().primary.strong.color.into(),
+                width: 1.0,
+                radius: 5.0.into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn title_bar(theme: &Theme) -> container::Style {
+        let palette = theme.extended_palette();
+
+        container::Style {
+            text_color: Some(palette.background.strong.text),
+            background: Some(palette.background.strong.color.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn pane(theme: &Theme) -> container::Style {
+        container::Style {
+            border: iced::Border {
+                color: theme.extended_palette().background.strong.color,
+                width: 2.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
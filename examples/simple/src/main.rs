@@ -1,6 +1,6 @@
 use iced::widget::{column, Container, Text};
 use iced::{Length, Padding, Task};
-use iced_reorderable::Column;
+use iced_reorderable::{list, ItemStatus};
 
 pub fn main() -> iced::Result {
     iced::application("Reorderable column", Simple::update, Simple::view)
@@ -9,44 +9,33 @@ pub fn main() -> iced::Result {
 }
 
 struct Simple {
-    items: Vec<String>,
-    dragged: Option<usize>,
+    items: list::State<String>,
 }
 
 impl Default for Simple {
     fn default() -> Self {
         Self {
-            items: vec![
+            items: list::State::new(vec![
                 "Tomato".to_string(),
                 "Lettuce".to_string(),
                 "Broccoli".to_string(),
                 "Carrot".to_string(),
                 "Cucumber".to_string(),
-            ],
-            dragged: None,
+            ]),
         }
     }
 }
 
 #[derive(Debug, Clone)]
 enum Message {
-    Drag(usize, usize),
-    Drop(usize, usize),
-    Cancel(usize),
+    List(list::Event),
 }
 
 impl Simple {
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
-            Message::Drag(key, _loc) => {
-                self.dragged = Some(key);
-            }
-            Message::Drop(key, loc) => {
-                self.drop_item(key, loc);
-                self.dragged = None;
-            }
-            Message::Cancel(_key) => {
-                self.dragged = None;
+            Message::List(event) => {
+                self.items.update(event);
             }
         }
 
@@ -61,24 +50,20 @@ impl Simple {
             left: 8.0,
         };
 
-        let reorderable_items =
-            Column::from_iter(self.items.iter().enumerate().map(|(index, item)| {
-                let item_style = if Some(index) == self.dragged {
-                    style::item_dragged
-                } else {
-                    style::item_idle
+        let reorderable_items = self
+            .items
+            .view_with_status(Message::List, |item, status| {
+                let item_style = match status {
+                    ItemStatus::Dragged => style::item_dragged,
+                    _ => style::item_idle,
                 };
-                let content = Container::new(Text::new(item))
+                Container::new(Text::new(item))
                     .padding(ITEM_PADDING)
-                    .style(item_style);
-
-                (index, content.into())
-            }))
+                    .style(item_style)
+                    .into()
+            })
             .spacing(10)
             .padding(Padding::default())
-            .on_drag(|key, index| Message::Drag(key, index))
-            .on_drop(|key, index| Message::Drop(key, index))
-            .on_cancel(|key| Message::Cancel(key))
             .drop_position_marker(true);
 
         let title = Text::new("Drag and drop to rank the vegetables");
@@ -86,21 +71,6 @@ impl Simple {
 
         Container::new(content).center(Length::Fill)
     }
-
-    fn drop_item(&mut self, key: usize, mut loc: usize) {
-        if key > self.items.len() || key == loc || key + 1 == loc {
-            return;
-        }
-        if loc > key {
-            loc -= 1;
-        }
-        let slot = self.items.remove(key);
-        if loc < self.items.len() {
-            self.items.insert(loc, slot);
-        } else {
-            self.items.push(slot);
-        }
-    }
 }
 
 mod style {
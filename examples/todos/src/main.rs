@@ -1,8 +1,10 @@
 use iced::widget::{
-    column, container, row, Button, Checkbox, Container, Rule, Space, Text, TextInput, Toggler,
+    column, container, row, scrollable, Button, Checkbox, Container, Radio, Rule, Space, Text,
+    TextInput, Toggler,
 };
-use iced::{Center, Element, Length::*, Padding, Task};
-use iced_reorderable::Column;
+use iced::{Center, Element, Length::*, Padding, Task, Vector};
+use iced_reorderable::index::move_item;
+use iced_reorderable::{Column, ItemIndex, ItemStatus, Presentation, SlotIndex};
 
 pub fn main() -> iced::Result {
     iced::application("Todos", Todos::update, Todos::view)
@@ -10,18 +12,21 @@ pub fn main() -> iced::Result {
         .run()
 }
 
+const LIST: &str = "todos";
+
 struct Todos {
     items: Vec<(String, bool)>,
     input: String,
-    dragged: Option<usize>,
     options: Options,
 }
 
 struct Options {
     drop_position_marker: bool,
+    presentation: Presentation,
     drag_follow: bool,
     drag_lateral: bool,
     drag_center: bool,
+    reorder_buttons: bool,
 }
 
 impl Default for Todos {
@@ -33,7 +38,6 @@ impl Default for Todos {
                 ("Sleep".to_string(), false),
             ],
             input: "".to_string(),
-            dragged: None,
             options: Options::default(),
         }
     }
@@ -43,44 +47,39 @@ impl Default for Options {
     fn default() -> Self {
         Self {
             drop_position_marker: true,
+            presentation: Presentation::Marker,
             drag_follow: true,
             drag_lateral: true,
             drag_center: false,
+            reorder_buttons: false,
         }
     }
 }
 
 #[derive(Debug, Clone)]
 enum Message {
-    Grab(usize),
-    Drag(usize, usize),
     Drop(usize, usize),
-    Cancel(usize),
     Remove(usize),
     Add,
     ToggleItemChecked(usize, bool),
     TypeInput(String),
     SetDropPositionMarker(bool),
+    SetPresentation(Presentation),
     SetDragFollow(bool),
     SetDragLateral(bool),
     SetDragCenter(bool),
+    SetReorderButtons(bool),
+    AutoScroll(Vector),
 }
 
 impl Todos {
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
-            Message::Grab(key) => {
-                self.dragged = Some(key);
-            }
-            Message::Drag(key, _loc) => {
-                self.dragged = Some(key);
-            }
             Message::Drop(key, loc) => {
                 self.drop_item(key, loc);
-                self.dragged = None;
             }
-            Message::Cancel(_key) => {
-                self.dragged = None;
+            Message::AutoScroll(delta) => {
+                return iced_reorderable::scroll_by(LIST, delta);
             }
             Message::Remove(key) => {
                 self.items.remove(key);
@@ -102,6 +101,9 @@ impl Todos {
             Message::SetDropPositionMarker(value) => {
                 self.options.drop_position_marker = value;
             }
+            Message::SetPresentation(value) => {
+                self.options.presentation = value;
+            }
             Message::SetDragFollow(value) => {
                 self.options.drag_follow = value;
             }
@@ -111,6 +113,9 @@ impl Todos {
             Message::SetDragCenter(value) => {
                 self.options.drag_center = value;
             }
+            Message::SetReorderButtons(value) => {
+                self.options.reorder_buttons = value;
+            }
         }
 
         Task::none()
@@ -121,6 +126,33 @@ impl Todos {
             row![Toggler::new(self.options.drop_position_marker)
                 .label("Show drop position marker")
                 .on_toggle(|v| Message::SetDropPositionMarker(v))],
+            row![
+                Radio::new(
+                    "Marker",
+                    Presentation::Marker,
+                    Some(self.options.presentation),
+                    Message::SetPresentation
+                ),
+                Radio::new(
+                    "Gap highlight",
+                    Presentation::GapHighlight,
+                    Some(self.options.presentation),
+                    Message::SetPresentation
+                ),
+                Radio::new(
+                    "Displace",
+                    Presentation::Displace,
+                    Some(self.options.presentation),
+                    Message::SetPresentation
+                ),
+                Radio::new(
+                    "Gap",
+                    Presentation::Gap,
+                    Some(self.options.presentation),
+                    Message::SetPresentation
+                ),
+            ]
+            .spacing(20),
             row![Toggler::new(self.options.drag_follow)
                 .label("Dragged item follows cursor")
                 .on_toggle(|v| Message::SetDragFollow(v))],
@@ -144,6 +176,9 @@ impl Todos {
                         None
                     })
             ],
+            row![Toggler::new(self.options.reorder_buttons)
+                .label("Show reorder buttons")
+                .on_toggle(|v| Message::SetReorderButtons(v))],
         ]
         .spacing(10)
         .padding(10);
@@ -162,7 +197,8 @@ impl Todos {
         .spacing(10);
 
         let reorderable_items =
-            Column::from_iter(self.items.iter().enumerate().map(|(index, item)| {
+            Column::with_children_status(0..self.items.len(), |index, status| {
+                let item = &self.items[index];
                 let remove_button = Button::new(Text::new("⌫").size(24))
                     .on_press(Message::Remove(index))
                     .padding(Padding {
@@ -187,49 +223,36 @@ impl Todos {
                 })
                 .align_y(Center);
 
-                let item_style = if Some(index) == self.dragged {
-                    style::item_dragged
-                } else {
-                    style::item_idle
+                let item_style = match status {
+                    ItemStatus::Idle => style::item_idle,
+                    ItemStatus::Grabbed | ItemStatus::Dragged => style::item_dragged,
                 };
-                let content = Container::new(row).style(item_style);
-
-                (index, content.into())
-            }))
+                Container::new(row).style(item_style).into()
+            })
+            .id(LIST)
             .spacing(12)
             .padding(10)
-            .on_grab(Message::Grab)
-            .on_drag(Message::Drag)
             .on_drop(Message::Drop)
-            .on_cancel(Message::Cancel)
+            .on_auto_scroll(Message::AutoScroll)
             .drop_position_marker(self.options.drop_position_marker)
+            .presentation(self.options.presentation)
             .drag_follow(self.options.drag_follow)
             .drag_lateral(self.options.drag_lateral)
-            .drag_center(self.options.drag_center);
+            .drag_center(self.options.drag_center)
+            .reorder_buttons(self.options.reorder_buttons);
 
         container(column![
             container(options.height(Shrink).width(Fill)).style(style::options_container),
             Rule::horizontal(1),
             add_item_row.height(Shrink),
-            reorderable_items.height(Fill)
+            scrollable(reorderable_items).height(Fill)
         ])
         .center(Fill)
         .into()
     }
 
-    fn drop_item(&mut self, key: usize, mut loc: usize) {
-        if key > self.items.len() || key == loc || key + 1 == loc {
-            return;
-        }
-        if loc > key {
-            loc -= 1;
-        }
-        let slot = self.items.remove(key);
-        if loc < self.items.len() {
-            self.items.insert(loc, slot);
-        } else {
-            self.items.push(slot);
-        }
+    fn drop_item(&mut self, key: usize, loc: usize) {
+        move_item(&mut self.items, ItemIndex(key), SlotIndex(loc));
     }
 }
 
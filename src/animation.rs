@@ -0,0 +1,146 @@
+//! Animation primitives for the transitions of the reorderable widgets.
+
+use iced::time::{Duration, Instant};
+
+/// An easing curve mapping linear animation progress to eased progress.
+///
+/// All curves map `0.0` to `0.0` and `1.0` to `1.0`. Progress outside of `0.0..=1.0`
+/// is clamped before the curve is applied.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Easing {
+    /// Constant speed over the whole animation.
+    Linear,
+    /// Starts slow and accelerates towards the end.
+    EaseIn,
+    /// Starts fast and decelerates towards the end.
+    #[default]
+    EaseOut,
+    /// Starts slow, accelerates and decelerates towards the end.
+    EaseInOut,
+    /// A custom easing function.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Returns the eased progress for the given linear progress `t`.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) * 0.5
+                }
+            }
+            Self::Custom(f) => f(t),
+        }
+    }
+}
+
+impl From<fn(f32) -> f32> for Easing {
+    fn from(f: fn(f32) -> f32) -> Self {
+        Self::Custom(f)
+    }
+}
+
+/// A source of progress for the transitions of the reorderable widgets.
+///
+/// The widgets use [`Timed`] by default. Implement this trait to drive the transitions
+/// from an animation clock the application already runs instead.
+pub trait DragAnimator {
+    /// Returns the eased progress of a transition started at `start`, in `0.0..=1.0`.
+    fn progress(&self, start: Instant, now: Instant) -> f32;
+
+    /// Returns whether a transition started at `start` is still running at `now`.
+    ///
+    /// The widgets keep requesting redraws while a transition is running.
+    fn is_running(&self, start: Instant, now: Instant) -> bool {
+        self.progress(start, now) < 1.0
+    }
+}
+
+/// The built-in [`DragAnimator`], interpolating over a fixed duration with an [`Easing`].
+#[derive(Debug, Clone, Copy)]
+pub struct Timed {
+    /// The duration of a transition.
+    pub duration: Duration,
+    /// The easing curve of a transition.
+    pub easing: Easing,
+}
+
+impl Timed {
+    /// Creates a new [`Timed`] animator with the given duration and the default [`Easing`].
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the [`Easing`] of the [`Timed`] animator.
+    pub fn easing(mut self, easing: impl Into<Easing>) -> Self {
+        self.easing = easing.into();
+        self
+    }
+}
+
+impl Default for Timed {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(150))
+    }
+}
+
+impl DragAnimator for Timed {
+    fn progress(&self, start: Instant, now: Instant) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        let elapsed = now.saturating_duration_since(start);
+
+        self.easing
+            .apply(elapsed.as_secs_f32() / self.duration.as_secs_f32())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EASINGS: [Easing; 5] = [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+        Easing::Custom(f32::sqrt),
+    ];
+
+    #[test]
+    fn apply_keeps_the_ends() {
+        for easing in EASINGS {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn apply_clamps_the_progress() {
+        for easing in EASINGS {
+            assert_eq!(easing.apply(-1.0), 0.0);
+            assert_eq!(easing.apply(2.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn apply_shapes_the_progress() {
+        assert_eq!(Easing::Linear.apply(0.25), 0.25);
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert_eq!(Easing::Custom(f32::sqrt).apply(0.25), 0.5);
+    }
+}
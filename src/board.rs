@@ -0,0 +1,944 @@
+//! Lay out keyed cards in several lanes side by side, and move them within and across the
+//! lanes by dragging and dropping.
+use crate::flex::{self, DragState};
+use crate::{Catalog, Status, Style, StyleFn};
+
+use iced::advanced::layout;
+use iced::advanced::layout::flex::Axis;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::mouse;
+use iced::Event;
+use iced::{Element, Length, Padding, Pixels, Point, Rectangle, Size, Vector};
+
+/// A container that distributes its [`Lane`]s horizontally, each stacking its keyed cards
+/// vertically, and allows moving the cards within and across the lanes by dragging and
+/// dropping.
+///
+/// Drops are reported with the key of the dragged card, the index of the lane it was
+/// dragged from, the index of the lane it was dropped on, and the index of the drop
+/// location among the cards of that lane. Drop locations use the same indexing as the
+/// [`Column`](crate::Column): the index of the card the dragged card is dropped in front of,
+/// or the number of cards when dropped after the last one. Within the source lane, the
+/// dragged card itself is counted as well.
+///
+/// The keys of the cards must be unique across all the lanes of the [`Board`].
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::text;
+/// use iced_reorderable::board::{Board, Lane};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Moved(u64, usize, usize, usize),
+/// }
+///
+/// fn view(lanes: &[(String, Vec<(u64, String)>)]) -> iced::Element<'_, Message> {
+///     Board::with_lanes(lanes.iter().map(|(title, cards)| {
+///         Lane::with_children(
+///             cards
+///                 .iter()
+///                 .map(|(id, card)| (*id, text(card.as_str()).into())),
+///         )
+///         .header(text(title.as_str()))
+///     }))
+///     .spacing(16)
+///     .card_spacing(8)
+///     .on_drop(Message::Moved)
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Board<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    spacing: f32,
+    card_spacing: f32,
+    padding: Padding,
+    lane_padding: Padding,
+    lane_width: Option<f32>,
+    width: Length,
+    height: Length,
+    lanes: Vec<Lane<'a, Key, Message, Theme, Renderer>>,
+    class: Theme::Class<'a>,
+    on_drag: Option<MoveFn<'a, Key, Message>>,
+    on_drop: Option<MoveFn<'a, Key, Message>>,
+    on_cancel: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    drop_position_marker: bool,
+    drag_follow: bool,
+}
+
+/// A message produced with the key of a card, its lane, the target lane and the index
+/// among the cards of the target lane.
+type MoveFn<'a, Key, Message> = Box<dyn Fn(Key, usize, usize, usize) -> Message + 'a>;
+
+/// A lane of a [`Board`], with an optional header and keyed cards.
+#[allow(missing_debug_implementations)]
+pub struct Lane<'a, Key, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    header: Option<Element<'a, Message, Theme, Renderer>>,
+    keys: Vec<Key>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Lane<'a, Key, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates an empty [`Lane`].
+    pub fn new() -> Self {
+        Self {
+            header: None,
+            keys: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Lane`] with the given keys and cards.
+    pub fn with_children(
+        children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        Self::new().extend(children)
+    }
+
+    /// Sets the header of the [`Lane`], shown above its cards.
+    ///
+    /// The header cannot be dragged, and cards are never dropped in front of it.
+    pub fn header(mut self, header: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Adds a card to the [`Lane`].
+    pub fn push(
+        mut self,
+        key: Key,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.keys.push(key);
+        self.children.push(child.into());
+        self
+    }
+
+    /// Extends the [`Lane`] with the given cards.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        children
+            .into_iter()
+            .fold(self, |lane, (key, child)| lane.push(key, child))
+    }
+
+    /// Returns the header and the cards of the [`Lane`], in the order of their trees.
+    fn elements(&self) -> impl Iterator<Item = &Element<'a, Message, Theme, Renderer>> {
+        self.header.iter().chain(&self.children)
+    }
+
+    /// Splits the trees of the [`Lane`] into the tree of the header and the trees of
+    /// the cards.
+    fn split_trees<'b>(&self, trees: &'b mut [Tree]) -> (Option<&'b mut Tree>, &'b mut [Tree]) {
+        let headers = usize::from(self.header.is_some()).min(trees.len());
+        let (header, cards) = trees.split_at_mut(headers);
+
+        (header.first_mut(), cards)
+    }
+
+    /// Returns the tree of the [`Lane`], holding the trees of its header and cards.
+    fn tree(&self) -> Tree {
+        let mut tree = Tree::empty();
+        tree.children = self
+            .elements()
+            .map(|child| Tree::new(child.as_widget()))
+            .collect();
+        tree
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Default for Lane<'a, Key, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Board<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates an empty [`Board`].
+    pub fn new() -> Self {
+        Self {
+            spacing: 0.0,
+            card_spacing: 0.0,
+            padding: Padding::ZERO,
+            lane_padding: Padding::ZERO,
+            lane_width: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            lanes: Vec::new(),
+            class: Theme::default(),
+            on_drag: None,
+            on_drop: None,
+            on_cancel: None,
+            drop_position_marker: true,
+            drag_follow: false,
+        }
+    }
+
+    /// Creates a [`Board`] with the given lanes.
+    pub fn with_lanes(
+        lanes: impl IntoIterator<Item = Lane<'a, Key, Message, Theme, Renderer>>,
+    ) -> Self {
+        lanes.into_iter().fold(Self::new(), Self::push)
+    }
+
+    /// Sets the horizontal spacing _between_ the lanes.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the cards of a lane, and between the header and
+    /// the first card.
+    pub fn card_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.card_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Board`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each lane of the [`Board`].
+    pub fn lane_padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.lane_padding = padding.into();
+        self
+    }
+
+    /// Sets the fixed width of each lane of the [`Board`].
+    ///
+    /// By default, the lanes share the width of the [`Board`] equally.
+    pub fn lane_width(mut self, lane_width: impl Into<Pixels>) -> Self {
+        self.lane_width = Some(lane_width.into().0);
+        self
+    }
+
+    /// Sets the width of the [`Board`].
+    ///
+    /// By default, the [`Board`] fills the available width.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Board`].
+    ///
+    /// The lanes stretch to the height of the [`Board`], so that cards can be dropped
+    /// below the last card of a short lane.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Adds a [`Lane`] to the [`Board`].
+    pub fn push(mut self, lane: Lane<'a, Key, Message, Theme, Renderer>) -> Self {
+        self.lanes.push(lane);
+        self
+    }
+
+    /// Sets the style of the [`Board`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Board`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the message that will be produced when dragging starts or the dragged card has
+    /// been dragged to another position in the [`Board`].
+    ///
+    /// The message will be produced with the key of the dragged card, the index of its
+    /// lane, the index of the lane it is dragged over, and the index of the drag position
+    /// among the cards of that lane.
+    pub fn on_drag<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, usize, usize, usize) -> Message + 'a,
+    {
+        self.on_drag = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when the dragged card is dropped on the
+    /// [`Board`].
+    ///
+    /// The message will be produced with the key of the dragged card, the index of its
+    /// lane, the index of the lane it is dropped on, and the index of the drop position
+    /// among the cards of that lane.
+    pub fn on_drop<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, usize, usize, usize) -> Message + 'a,
+    {
+        self.on_drop = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when the user cancels active dragging by
+    /// right-clicking, by pressing Escape, or when the dragging touch is lost. Active
+    /// dragging is canceled as well when the window loses focus.
+    pub fn on_cancel<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_cancel = Some(Box::new(message));
+        self
+    }
+
+    /// Sets whether a marker line will be shown at the position where the dragged card
+    /// would be dropped.
+    pub fn drop_position_marker(mut self, drop_position_marker: bool) -> Self {
+        self.drop_position_marker = drop_position_marker;
+        self
+    }
+
+    /// Sets whether a card should follow the cursor or touch while being dragged.
+    pub fn drag_follow(mut self, drag_follow: bool) -> Self {
+        self.drag_follow = drag_follow;
+        self
+    }
+
+    /// Returns whether any lane of the [`Board`] contains a card with the given key.
+    fn contains(&self, key: &Key) -> bool {
+        self.lane_of(key).is_some()
+    }
+
+    /// Returns the index of the lane containing the card with the given key.
+    fn lane_of(&self, key: &Key) -> Option<usize> {
+        self.lanes.iter().position(|lane| lane.keys.contains(key))
+    }
+
+    /// Returns the drop location at the given `position`, flattened over the drop
+    /// locations of all the lanes for the [`DragState`].
+    ///
+    /// The card is dropped on the lane horizontally closest to the position.
+    fn drop_location(&self, layout: &Layout, position: Point) -> usize {
+        let distance = |bounds: Rectangle| {
+            (bounds.x - position.x)
+                .max(position.x - (bounds.x + bounds.width))
+                .max(0.0)
+        };
+        let Some((lane, lane_layout)) = layout
+            .children()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| distance(a.bounds()).total_cmp(&distance(b.bounds())))
+        else {
+            return 0;
+        };
+
+        self.lanes[..lane]
+            .iter()
+            .map(|lane| lane.keys.len() + 1)
+            .sum::<usize>()
+            + flex::drop_location(&Axis::Vertical, &cards_layout(lane_layout), position)
+    }
+
+    /// Returns the lane and the index among its cards of a flattened drop location.
+    fn lane_location(&self, mut drop_location: usize) -> (usize, usize) {
+        for (index, lane) in self.lanes.iter().enumerate() {
+            if drop_location <= lane.keys.len() {
+                return (index, drop_location);
+            }
+            drop_location -= lane.keys.len() + 1;
+        }
+        (self.lanes.len().saturating_sub(1), drop_location)
+    }
+
+    /// Returns the bounds of the drop position marker line for a flattened drop location.
+    fn marker_bounds(&self, layout: &Layout, drop_location: usize) -> Option<Rectangle> {
+        let (lane, index) = self.lane_location(drop_location);
+        let cards = cards_layout(layout.children().nth(lane)?);
+
+        if cards.children().count() == 0 {
+            let bounds = cards.bounds();
+            return Some(Rectangle {
+                height: flex::MARKER_LINE_WIDTH,
+                ..bounds
+            });
+        }
+        flex::marker_bounds(
+            &Axis::Vertical,
+            &cards,
+            Padding::ZERO,
+            self.card_spacing,
+            index,
+        )
+    }
+
+    /// Returns the key of the card following the cursor and its translation from its laid
+    /// out position, if any.
+    fn dragged_translation(&self, state: &State<Key>, layout: Layout<'_>) -> Option<(Key, Vector)> {
+        let cards = self
+            .lanes
+            .iter()
+            .zip(layout.children())
+            .flat_map(|(lane, lane_layout)| {
+                lane.keys.iter().zip(cards_layout(lane_layout).children())
+            });
+
+        state
+            .drag
+            .follow_translation(cards)
+            .filter(|_| self.drag_follow)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Default for Board<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> flex::Gesture<Key, Message>
+    for Board<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    type State = State<Key>;
+
+    fn drag<'s>(&self, state: &'s mut State<Key>) -> &'s mut DragState<Key> {
+        &mut state.drag
+    }
+
+    fn grab_at(
+        &mut self,
+        _state: &mut State<Key>,
+        _shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        _event: &Event,
+        position: Point,
+    ) -> Option<(Key, Rectangle)> {
+        self.lanes
+            .iter()
+            .zip(layout.children())
+            .find_map(|(lane, lane_layout)| {
+                flex::child_at(&lane.keys, cards_layout(lane_layout), position)
+            })
+    }
+
+    fn drop_location(&self, layout: Layout<'_>, _key: Key, position: Point) -> usize {
+        self.drop_location(&layout, position)
+    }
+
+    fn drag_message(&self, key: Key, drop_location: usize) -> Option<Message> {
+        self.on_drag
+            .as_deref()
+            .zip(self.lane_of(&key))
+            .map(|(on_drag, source)| {
+                let (target, index) = self.lane_location(drop_location);
+                on_drag(key, source, target, index)
+            })
+    }
+
+    fn drop_message(&self, key: Key, drop_location: usize) -> Option<Message> {
+        self.on_drop
+            .as_deref()
+            .zip(self.lane_of(&key))
+            .map(|(on_drop, source)| {
+                let (target, index) = self.lane_location(drop_location);
+                on_drop(key, source, target, index)
+            })
+    }
+
+    fn cancel_message(&self, key: Key) -> Option<Message> {
+        self.on_cancel.as_ref().map(|on_cancel| on_cancel(key))
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Board<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key>::default())
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.lanes.iter().map(Lane::tree).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        if state.drag.key().is_some_and(|key| !self.contains(&key)) {
+            state.drag = DragState::Idle;
+        }
+        tree.diff_children_custom(
+            &self.lanes,
+            |tree, lane| {
+                let elements: Vec<_> = lane.elements().collect();
+                tree.diff_children_custom(
+                    &elements,
+                    |tree, child| tree.diff(child.as_widget()),
+                    |child| Tree::new(child.as_widget()),
+                );
+            },
+            Lane::tree,
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .shrink(self.padding);
+        let count = self.lanes.len();
+        let lane_width = self.lane_width.unwrap_or_else(|| {
+            let spacing = self.spacing * count.saturating_sub(1) as f32;
+            ((limits.max().width - spacing) / count.max(1) as f32).max(0.0)
+        });
+        let inner_width = (lane_width - self.lane_padding.horizontal()).max(0.0);
+        let child_limits = layout::Limits::new(Size::ZERO, Size::new(inner_width, f32::INFINITY));
+
+        let mut lanes = Vec::with_capacity(count);
+        let mut content_height: f32 = 0.0;
+
+        for (lane, tree) in self.lanes.iter().zip(&mut tree.children) {
+            let (header_tree, card_trees) = lane.split_trees(&mut tree.children);
+            let header = match (&lane.header, header_tree) {
+                (Some(header), Some(tree)) => {
+                    header.as_widget().layout(tree, renderer, &child_limits)
+                }
+                _ => layout::Node::new(Size::ZERO),
+            };
+            let gap = if lane.header.is_some() {
+                self.card_spacing
+            } else {
+                0.0
+            };
+
+            let mut cards = Vec::with_capacity(lane.children.len());
+            let mut y = 0.0;
+            for (card, tree) in lane.children.iter().zip(card_trees) {
+                let node = card.as_widget().layout(tree, renderer, &child_limits);
+                let height = node.size().height;
+
+                cards.push(node.move_to(Point::new(0.0, y)));
+                y += height + self.card_spacing;
+            }
+            let cards_height = (y - self.card_spacing).max(0.0);
+
+            content_height = content_height.max(header.size().height + gap + cards_height);
+            lanes.push((header, gap, cards));
+        }
+
+        let content_width =
+            lane_width * count as f32 + self.spacing * count.saturating_sub(1) as f32;
+        let content_size = Size::new(content_width, content_height + self.lane_padding.vertical());
+        let size = limits
+            .resolve(self.width, self.height, content_size)
+            .expand(self.padding);
+        let lane_height = size.height - self.padding.vertical();
+
+        let nodes = lanes
+            .into_iter()
+            .enumerate()
+            .map(|(index, (header, gap, cards))| {
+                let header_height = header.size().height;
+                let cards_top = self.lane_padding.top + header_height + gap;
+                let cards_size = Size::new(
+                    inner_width,
+                    (lane_height - cards_top - self.lane_padding.bottom).max(0.0),
+                );
+
+                layout::Node::with_children(
+                    Size::new(lane_width, lane_height),
+                    vec![
+                        header.move_to(Point::new(self.lane_padding.left, self.lane_padding.top)),
+                        layout::Node::with_children(cards_size, cards)
+                            .move_to(Point::new(self.lane_padding.left, cards_top)),
+                    ],
+                )
+                .move_to(Point::new(
+                    self.padding.left + index as f32 * (lane_width + self.spacing),
+                    self.padding.top,
+                ))
+            })
+            .collect();
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let drag_state = tree.state.downcast_ref::<State<Key>>().drag;
+        if let Some((event, cursor)) =
+            flex::propagate_event_to_children(&drag_state, 0.0, event, cursor)
+        {
+            for ((lane, tree), lane_layout) in self
+                .lanes
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                let has_header = lane.header.is_some();
+                let child_layouts = header_layout(lane_layout)
+                    .filter(|_| has_header)
+                    .into_iter()
+                    .chain(cards_layout(lane_layout).children());
+
+                for ((child, state), child_layout) in lane
+                    .header
+                    .iter_mut()
+                    .chain(&mut lane.children)
+                    .zip(&mut tree.children)
+                    .zip(child_layouts)
+                {
+                    child.as_widget_mut().update(
+                        state,
+                        event,
+                        child_layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    );
+                }
+            }
+        }
+
+        flex::update(
+            self,
+            tree.state.downcast_mut::<State<Key>>(),
+            event,
+            layout,
+            cursor,
+            shell,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((lane, tree), lane_layout) in self
+                .lanes
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                let has_header = lane.header.is_some();
+                let child_layouts = header_layout(lane_layout)
+                    .filter(|_| has_header)
+                    .into_iter()
+                    .chain(cards_layout(lane_layout).children());
+
+                for ((child, state), child_layout) in
+                    lane.elements().zip(&mut tree.children).zip(child_layouts)
+                {
+                    child
+                        .as_widget()
+                        .operate(state, child_layout, renderer, operation);
+                }
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let drag_state = tree.state.downcast_ref::<State<Key>>().drag;
+        if !drag_state.is_idle() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.lanes
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .flat_map(|((lane, tree), lane_layout)| {
+                let has_header = lane.header.is_some();
+                let child_layouts = header_layout(lane_layout)
+                    .filter(|_| has_header)
+                    .into_iter()
+                    .chain(cards_layout(lane_layout).children());
+
+                lane.elements()
+                    .zip(&tree.children)
+                    .zip(child_layouts)
+                    .enumerate()
+                    .map(move |(index, ((child, state), child_layout))| {
+                        let is_card = lane.header.is_none() || index > 0;
+                        let child_interaction = child.as_widget().mouse_interaction(
+                            state,
+                            child_layout,
+                            cursor,
+                            viewport,
+                            renderer,
+                        );
+                        if is_card
+                            && self.on_drop.is_some()
+                            && cursor.is_over(child_layout.bounds())
+                        {
+                            mouse::Interaction::Pointer.max(child_interaction)
+                        } else {
+                            child_interaction
+                        }
+                    })
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(clipped_viewport) = layout.bounds().intersection(viewport) else {
+            return;
+        };
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let (dragged_key, dragged_translation) = match self.dragged_translation(state, layout) {
+            Some((key, translation)) => (Some(key), translation),
+            None => (None, Vector::ZERO),
+        };
+
+        let marker = match state.drag {
+            DragState::Dragged { drop_location, .. } if self.drop_position_marker => {
+                self.marker_bounds(&layout, drop_location)
+            }
+            _ => None,
+        };
+
+        let mut dragged = None;
+        for ((lane, tree), lane_layout) in self
+            .lanes
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|(_, lane_layout)| lane_layout.bounds().intersects(viewport))
+        {
+            let headers = usize::from(lane.header.is_some()).min(tree.children.len());
+            let (header_tree, card_trees) = tree.children.split_at(headers);
+
+            if let Some(((header, state), header_layout)) = lane
+                .header
+                .as_ref()
+                .zip(header_tree.first())
+                .zip(header_layout(lane_layout))
+            {
+                header.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    header_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+
+            for (((child, key), state), card_layout) in lane
+                .children
+                .iter()
+                .zip(&lane.keys)
+                .zip(card_trees)
+                .zip(cards_layout(lane_layout).children())
+                .filter(|(_, card_layout)| card_layout.bounds().intersects(viewport))
+            {
+                if Some(*key) == dragged_key {
+                    dragged = Some((child, state, card_layout));
+                    continue;
+                }
+                child.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    card_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+
+        if marker.is_some() || dragged.is_some() {
+            renderer.with_layer(clipped_viewport, |renderer| {
+                if let Some(marker) = marker {
+                    let marker_style = theme.style(&self.class, state.drag.status());
+                    flex::draw_marker(renderer, &Axis::Vertical, marker, viewport, marker_style);
+                }
+                if let Some((child, state, card_layout)) = dragged {
+                    renderer.with_translation(dragged_translation, |renderer| {
+                        child.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            style,
+                            card_layout,
+                            cursor,
+                            viewport,
+                        );
+                    });
+                }
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = Vec::new();
+
+        for ((lane, tree), lane_layout) in self
+            .lanes
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            let has_header = lane.header.is_some();
+            let child_layouts = header_layout(lane_layout)
+                .filter(|_| has_header)
+                .into_iter()
+                .chain(cards_layout(lane_layout).children());
+
+            children.extend(
+                lane.header
+                    .iter_mut()
+                    .chain(&mut lane.children)
+                    .zip(&mut tree.children)
+                    .zip(child_layouts)
+                    .filter_map(|((child, state), child_layout)| {
+                        child
+                            .as_widget_mut()
+                            .overlay(state, child_layout, renderer, translation)
+                    }),
+            );
+        }
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<Board<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: Board<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct State<K>
+where
+    K: Copy + PartialEq,
+{
+    drag: DragState<K>,
+}
+
+impl<Key> Default for State<Key>
+where
+    Key: Copy + PartialEq,
+{
+    fn default() -> Self {
+        Self {
+            drag: DragState::Idle,
+        }
+    }
+}
+
+/// Returns the layout of the header of a lane.
+fn header_layout<'a>(lane_layout: Layout<'a>) -> Option<Layout<'a>> {
+    lane_layout.children().next()
+}
+
+/// Returns the layout stacking the cards of a lane.
+fn cards_layout<'a>(lane_layout: Layout<'a>) -> Layout<'a> {
+    lane_layout
+        .children()
+        .nth(1)
+        .expect("A lane layout contains a header and cards layout")
+}
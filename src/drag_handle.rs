@@ -0,0 +1,274 @@
+//! Restrict grabbing the child elements to handles.
+use iced::advanced::layout;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{Id, Operation, Tree, Widget};
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::mouse;
+use iced::Event;
+use iced::{Element, Length, Pixels, Point, Rectangle, Size, Vector};
+
+/// The default [`DragHandle::min_size`], a comfortable target for pointers and fingers.
+const DEFAULT_MIN_SIZE: f32 = 24.0;
+
+/// An element wrapping content that grabs the [`Column`](crate::Column) child element
+/// containing it.
+///
+/// A child element containing [`DragHandle`]s can only be grabbed by pressing one of
+/// them, while the rest of it is left to its own widgets; child elements without
+/// handles can still be grabbed anywhere. Useful for rows full of text inputs or
+/// sliders.
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::{row, text, text_input};
+/// use iced_reorderable::{drag_handle, Column};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Renamed(usize, String),
+///     Dropped(usize, usize),
+/// }
+///
+/// fn view(names: &[String]) -> iced::Element<'_, Message> {
+///     Column::with_children(names.iter().enumerate().map(|(index, name)| {
+///         let input = text_input("Name", name)
+///             .on_input(move |name| Message::Renamed(index, name));
+///
+///         (index, row![drag_handle(text("::")), input].into())
+///     }))
+///     .on_drop(Message::Dropped)
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DragHandle<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    min_size: f32,
+}
+
+impl<'a, Message, Theme, Renderer> DragHandle<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a [`DragHandle`] wrapping the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            min_size: DEFAULT_MIN_SIZE,
+        }
+    }
+
+    /// Sets the minimum width and height of the [`DragHandle`].
+    ///
+    /// Smaller content is centered in a handle of this size, so that tiny grips stay easy
+    /// to hit. The size is limited by the space available to the handle. Defaults to 24
+    /// logical pixels.
+    pub fn min_size(mut self, min_size: impl Into<Pixels>) -> Self {
+        self.min_size = min_size.into().0;
+        self
+    }
+}
+
+/// Wraps the given content in a [`DragHandle`], so that the child element containing it
+/// can only be grabbed by pressing it.
+pub fn drag_handle<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DragHandle<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    DragHandle::new(content)
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragHandle<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        let size = content
+            .size()
+            .max(Size::new(self.min_size, self.min_size))
+            .min(limits.max());
+        let offset = Point::new(
+            ((size.width - content.size().width) / 2.0).max(0.0),
+            ((size.height - content.size().height) / 2.0).max(0.0),
+        );
+
+        layout::Node::with_children(size, vec![content.move_to(offset)])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            content_layout(layout),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.custom(&mut Handle(layout.bounds()), None);
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            content_layout(layout),
+            renderer,
+            operation,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout(layout),
+            cursor,
+            viewport,
+            renderer,
+        );
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab.max(interaction)
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout(layout),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            content_layout(layout),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DragHandle<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: DragHandle<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+/// Returns the layout of the content of a [`DragHandle`].
+fn content_layout(layout: Layout<'_>) -> Layout<'_> {
+    layout
+        .children()
+        .next()
+        .expect("a drag handle lays out its content")
+}
+
+/// The bounds of a [`DragHandle`], exposed to operations.
+struct Handle(Rectangle);
+
+/// An [`Operation`] collecting the bounds of the [`DragHandle`]s in a child element.
+#[derive(Debug, Default)]
+pub(crate) struct FindHandles {
+    pub(crate) bounds: Vec<Rectangle>,
+}
+
+impl Operation for FindHandles {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, _id: Option<&Id>) {
+        if let Some(Handle(bounds)) = state.downcast_ref::<Handle>() {
+            self.bounds.push(*bounds);
+        }
+    }
+}
@@ -0,0 +1,375 @@
+//! Show a reorderable menu in an overlay below an underlay element.
+use iced::advanced::layout;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{Operation, Tree, Widget};
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::keyboard;
+use iced::mouse;
+use iced::touch;
+use iced::Event;
+use iced::{Element, Length, Point, Rectangle, Size, Vector};
+
+/// An element that shows a menu in an overlay below it while expanded.
+///
+/// The menu is typically a reorderable [`Column`](crate::Column), wrapped in a container
+/// providing its background. Unlike an inline [`Column`](crate::Column), the menu is laid
+/// out on top of the rest of the user interface, and is moved above the underlay if there
+/// is not enough space below it.
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::{button, container, text};
+/// use iced_reorderable::{Column, Dropdown};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggle,
+///     Dismiss,
+///     Dropped(usize, usize),
+/// }
+///
+/// fn view(entries: &[String], expanded: bool) -> iced::Element<'_, Message> {
+///     let menu = Column::with_children(
+///         entries
+///             .iter()
+///             .enumerate()
+///             .map(|(index, entry)| (index, text(entry).into())),
+///     )
+///     .on_drop(Message::Dropped);
+///
+///     Dropdown::new(
+///         button("Order").on_press(Message::Toggle),
+///         container(menu).style(container::bordered_box),
+///         expanded,
+///     )
+///     .on_dismiss(Message::Dismiss)
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Dropdown<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::Renderer,
+{
+    underlay: Element<'a, Message, Theme, Renderer>,
+    menu: Element<'a, Message, Theme, Renderer>,
+    expanded: bool,
+    menu_width: Option<f32>,
+    on_dismiss: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> Dropdown<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a [`Dropdown`] with the given underlay and menu elements.
+    ///
+    /// The menu is shown while `expanded` is `true`.
+    pub fn new(
+        underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+        menu: impl Into<Element<'a, Message, Theme, Renderer>>,
+        expanded: bool,
+    ) -> Self {
+        Self {
+            underlay: underlay.into(),
+            menu: menu.into(),
+            expanded,
+            menu_width: None,
+            on_dismiss: None,
+        }
+    }
+
+    /// Sets the width of the menu of the [`Dropdown`].
+    ///
+    /// By default, the menu is as wide as the underlay.
+    pub fn menu_width(mut self, width: impl Into<iced::Pixels>) -> Self {
+        self.menu_width = Some(width.into().0);
+        self
+    }
+
+    /// Sets the message that will be produced when the expanded menu is dismissed by
+    /// pressing outside of it or pressing Escape.
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Dropdown<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&self.menu)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay, &self.menu]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.underlay.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let [underlay_tree, menu_tree] = tree.children.as_mut_slice() else {
+            return None;
+        };
+
+        let underlay =
+            self.underlay
+                .as_widget_mut()
+                .overlay(underlay_tree, layout, renderer, translation);
+
+        let menu = self.expanded.then(|| {
+            let bounds = layout.bounds();
+
+            overlay::Element::new(Box::new(Menu {
+                menu: &mut self.menu,
+                tree: menu_tree,
+                position: bounds.position() + translation,
+                target_height: bounds.height,
+                width: self.menu_width.unwrap_or(bounds.width),
+                on_dismiss: self.on_dismiss.clone(),
+                viewport: Rectangle::default(),
+            }))
+        });
+
+        let children: Vec<_> = underlay.into_iter().chain(menu).collect();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Dropdown<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: Dropdown<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+/// The overlay of an expanded [`Dropdown`].
+struct Menu<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    menu: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    position: Point,
+    target_height: f32,
+    width: f32,
+    on_dismiss: Option<Message>,
+    viewport: Rectangle,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Menu<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.viewport = Rectangle::with_size(bounds);
+
+        let space_below = bounds.height - (self.position.y + self.target_height);
+        let space_above = self.position.y;
+        let is_below = space_below >= space_above;
+
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                (bounds.width - self.position.x).max(0.0),
+                if is_below { space_below } else { space_above },
+            ),
+        )
+        .width(self.width);
+
+        let node = self.menu.as_widget().layout(self.tree, renderer, &limits);
+        let size = node.size();
+
+        node.move_to(if is_below {
+            self.position + Vector::new(0.0, self.target_height)
+        } else {
+            self.position - Vector::new(0.0, size.height)
+        })
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let viewport = self.viewport;
+        self.menu.as_widget_mut().update(
+            self.tree, event, layout, cursor, renderer, clipboard, shell, &viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let dismissed = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => !cursor.is_over(layout.bounds()),
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => true,
+            _ => false,
+        };
+
+        if dismissed {
+            if let Some(on_dismiss) = &self.on_dismiss {
+                shell.publish(on_dismiss.clone());
+                shell.capture_event();
+            }
+        }
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        self.menu
+            .as_widget()
+            .operate(self.tree, layout, renderer, operation);
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.menu
+            .as_widget()
+            .mouse_interaction(self.tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.menu.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &self.viewport,
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Report integration mistakes of the reorderable widgets.
+use std::fmt;
+
+/// A misconfiguration of a [`Column`](crate::Column) detected at runtime.
+///
+/// Most mistakes do not break the widget outright, they silently degrade its behavior,
+/// e.g. the child trees of children sharing a key get mixed up. In debug builds, the
+/// [`Column`](crate::Column) checks itself and produces the message set with
+/// [`Column::on_error`](crate::Column::on_error) once for each error it detects. Release
+/// builds skip the checks.
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::text;
+/// use iced_reorderable::{Column, ReorderError};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(usize, usize),
+///     Misused(ReorderError),
+/// }
+///
+/// fn view(items: &[String]) -> iced::Element<'_, Message> {
+///     Column::with_children(
+///         items
+///             .iter()
+///             .enumerate()
+///             .map(|(index, item)| (index, text(item).into())),
+///     )
+///     .on_drop(Message::Dropped)
+///     .on_error(Message::Misused)
+///     .into()
+/// }
+///
+/// fn update(message: Message) {
+///     if let Message::Misused(error) = message {
+///         eprintln!("reorderable column: {error}");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReorderError {
+    /// Two child elements share the same key, so their states and drags get mixed up.
+    DuplicateKey {
+        /// The index of the first child element with the key.
+        first: usize,
+        /// The index of the second child element with the key.
+        second: usize,
+    },
+    /// The number of keys differs from the number of child elements.
+    KeysMismatch {
+        /// The number of keys.
+        keys: usize,
+        /// The number of child elements.
+        children: usize,
+    },
+    /// A child element is dragged with the drop position marker shown, but no drop
+    /// message is set, so the drop has no effect.
+    MissingDropMessage,
+    /// [`Column::drag_center`](crate::Column::drag_center) is enabled without
+    /// [`Column::drag_follow`](crate::Column::drag_follow), so it has no effect.
+    DragCenterWithoutFollow,
+}
+
+impl fmt::Display for ReorderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReorderError::DuplicateKey { first, second } => write!(
+                f,
+                "the children at indices {first} and {second} have the same key"
+            ),
+            ReorderError::KeysMismatch { keys, children } => {
+                write!(f, "{keys} keys were given for {children} children")
+            }
+            ReorderError::MissingDropMessage => write!(
+                f,
+                "a child is dragged, but no drop message is set, so the drop has no effect"
+            ),
+            ReorderError::DragCenterWithoutFollow => write!(
+                f,
+                "drag_center has no effect, since drag_follow is disabled"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReorderError {}
+
+/// Returns the indices of the first pair of equal keys, if any.
+pub(crate) fn find_duplicate<Key: PartialEq>(keys: &[Key]) -> Option<(usize, usize)> {
+    keys.iter().enumerate().find_map(|(second, key)| {
+        keys[..second]
+            .iter()
+            .position(|other| other == key)
+            .map(|first| (first, second))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_duplicate_of_unique_keys() {
+        assert_eq!(find_duplicate::<usize>(&[]), None);
+        assert_eq!(find_duplicate(&[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn find_duplicate_returns_the_first_pair() {
+        assert_eq!(find_duplicate(&[1, 2, 1]), Some((0, 2)));
+        assert_eq!(find_duplicate(&[1, 2, 2, 1]), Some((1, 2)));
+    }
+
+    #[test]
+    fn display_keys_mismatch() {
+        let error = ReorderError::KeysMismatch {
+            keys: 2,
+            children: 3,
+        };
+
+        assert_eq!(error.to_string(), "2 keys were given for 3 children");
+    }
+}
@@ -0,0 +1,2101 @@
+//! The drag logic shared by the reorderable widgets, independent of their main axis.
+//!
+//! The drag state machine, the slot math locating drop positions among the laid out
+//! children and the marker rendering all live here, so that each public widget only
+//! adapts them to its own layout and they behave the same across all of them.
+use crate::operation::DragInfo;
+use crate::{ItemStatus, MarkerStyle, Status, Style};
+
+use iced::advanced::layout::flex::Axis;
+use iced::advanced::renderer;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::border::Radius;
+use iced::keyboard;
+use iced::mouse;
+use iced::time::{Duration, Instant};
+use iced::touch;
+use iced::window;
+use iced::{Border, Color, Event, Padding, Point, Rectangle, Size, Vector};
+
+/// The width of the drop location marker line.
+pub(crate) const MARKER_LINE_WIDTH: f32 = 2.0;
+
+/// The distance from the edges of the viewport within which dragging scrolls it.
+const AUTO_SCROLL_ZONE: f32 = 40.0;
+
+/// The amount scrolled per frame when dragging at the very edge of the viewport.
+const AUTO_SCROLL_SPEED: f32 = 16.0;
+
+/// The length of the dashes of a dashed drop location marker line.
+const MARKER_DASH_LENGTH: f32 = 6.0;
+
+/// The length of the gaps between the dashes of a dashed drop location marker line.
+const MARKER_DASH_GAP: f32 = 4.0;
+
+/// The current dragging state of a reorderable widget.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub(crate) enum DragState<K>
+where
+    K: Copy + PartialEq,
+{
+    /// No child element is being dragged.
+    #[default]
+    Idle,
+    /// A child element is grabbed for dragging, but has not been moved yet.
+    ///
+    /// The `offset` is the grab position relative to the top-left corner of the
+    /// child element. The child element cannot be dragged before `hold_until`, if set.
+    Grabbed {
+        key: K,
+        origin: Point,
+        offset: Vector,
+        hold_until: Option<Instant>,
+    },
+    /// A child element is being dragged.
+    Dragged {
+        key: K,
+        origin: Point,
+        offset: Vector,
+        position: Point,
+        drop_location: usize,
+    },
+}
+
+impl<K> DragState<K>
+where
+    K: Copy + PartialEq,
+{
+    /// Returns the [`Status`] of a widget in this drag state, styling it with its theme.
+    pub(crate) fn status(&self) -> Status {
+        match self {
+            Self::Idle => Status::Idle,
+            Self::Grabbed { .. } => Status::Grabbed,
+            Self::Dragged { .. } => Status::Dragging,
+        }
+    }
+
+    pub(crate) fn key(&self) -> Option<K> {
+        match self {
+            Self::Idle => None,
+            Self::Grabbed { key, .. } => Some(*key),
+            Self::Dragged { key, .. } => Some(*key),
+        }
+    }
+
+    pub(crate) fn item_status(&self, item_key: K) -> ItemStatus {
+        match self {
+            Self::Grabbed { key, .. } if *key == item_key => ItemStatus::Grabbed,
+            Self::Dragged { key, .. } if *key == item_key => ItemStatus::Dragged,
+            _ => ItemStatus::Idle,
+        }
+    }
+
+    pub(crate) fn is_idle(&self) -> bool {
+        matches!(self, Self::Idle)
+    }
+
+    pub(crate) fn last_position(&self) -> Option<Point> {
+        match self {
+            Self::Idle => None,
+            Self::Grabbed { origin, .. } => Some(*origin),
+            Self::Dragged { position, .. } => Some(*position),
+        }
+    }
+
+    pub(crate) fn drop_location(&self) -> Option<usize> {
+        match self {
+            Self::Dragged { drop_location, .. } => Some(*drop_location),
+            _ => None,
+        }
+    }
+
+    /// Returns the drag in this drag state as reported by
+    /// [`drag_state`](crate::operation::drag_state).
+    pub(crate) fn info(&self) -> Option<DragInfo<K>> {
+        self.key().map(|key| DragInfo {
+            key,
+            drop_location: self.drop_location(),
+        })
+    }
+
+    /// Returns the key of the dragged child element and its translation from its laid out
+    /// position to the cursor, looking it up among the given keyed layouts of the children.
+    ///
+    /// The translation is relative to the current layout of the child element, so it stays
+    /// under the cursor even if the layout changes during the drag.
+    pub(crate) fn follow_translation<'a, 'b>(
+        &self,
+        items: impl IntoIterator<Item = (&'a K, Layout<'b>)>,
+    ) -> Option<(K, Vector)>
+    where
+        K: 'a,
+    {
+        let Self::Dragged {
+            key,
+            offset,
+            position,
+            ..
+        } = *self
+        else {
+            return None;
+        };
+        let (_, item_layout) = items.into_iter().find(|(item_key, _)| **item_key == key)?;
+
+        Some((key, position - (item_layout.bounds().position() + offset)))
+    }
+}
+
+/// Returns whether to propagate an [`Event`] to the children of a reorderable widget.
+///
+/// Will return `false` for mouse and touch events if a child element is being dragged.
+/// A grabbed child element is only considered dragged before it has been moved if the
+/// drag threshold is zero.
+pub(crate) fn propagate_event_to_children<'a, Key>(
+    drag_state: &DragState<Key>,
+    drag_threshold: f32,
+    event: &'a Event,
+    cursor: mouse::Cursor,
+) -> Option<(&'a Event, mouse::Cursor)>
+where
+    Key: Copy + PartialEq,
+{
+    let is_shielded = match drag_state {
+        DragState::Idle => false,
+        DragState::Grabbed { .. } => drag_threshold <= 0.0,
+        DragState::Dragged { .. } => true,
+    };
+    if is_shielded {
+        match event {
+            Event::Touch(touch::Event::FingerMoved { .. })
+            | Event::Mouse(mouse::Event::CursorMoved { .. }) => None,
+            _ => Some((event, mouse::Cursor::Unavailable)),
+        }
+    } else {
+        Some((event, cursor))
+    }
+}
+
+/// The clock a reorderable widget reads the current time from, the system clock unless
+/// one is set with the `clock` method of the widget.
+#[derive(Default)]
+pub(crate) struct TimeSource<'a>(Option<Box<dyn Fn() -> Instant + 'a>>);
+
+impl<'a> TimeSource<'a> {
+    /// Creates a [`TimeSource`] reading the given clock.
+    pub(crate) fn new(clock: impl Fn() -> Instant + 'a) -> Self {
+        Self(Some(Box::new(clock)))
+    }
+
+    /// Returns the current time.
+    pub(crate) fn now(&self) -> Instant {
+        self.0.as_ref().map_or_else(Instant::now, |clock| clock())
+    }
+
+    /// Returns the [`Hold`] of the given mouse hold delay at the current time, if any.
+    pub(crate) fn hold(&self, delay: Option<Duration>) -> Option<Hold> {
+        delay.map(|delay| Hold {
+            delay,
+            now: self.now(),
+        })
+    }
+}
+
+/// The delay a mouse press has to be held on a child element of a reorderable widget
+/// before it can be dragged, with the current time.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Hold {
+    pub(crate) delay: Duration,
+    pub(crate) now: Instant,
+}
+
+/// The steps of the drag gesture a reorderable widget adapts to its own layout and
+/// messages, driven by [`update`].
+///
+/// The provided methods implement the basic gesture producing the messages of the widget,
+/// and are overridden by widgets extending it.
+pub(crate) trait Gesture<Key, Message>
+where
+    Key: Copy + PartialEq,
+{
+    /// The state of the widget holding its [`DragState`].
+    type State;
+
+    /// Returns the [`DragState`] in the given widget state.
+    fn drag<'s>(&self, state: &'s mut Self::State) -> &'s mut DragState<Key>;
+
+    /// Returns the key and the bounds of the child element grabbed by the press `event` at
+    /// the given position, if any.
+    fn grab_at(
+        &mut self,
+        state: &mut Self::State,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        event: &Event,
+        position: Point,
+    ) -> Option<(Key, Rectangle)>;
+
+    /// Returns the drop location of the child element with the given key dragged to the
+    /// given position.
+    fn drop_location(&self, layout: Layout<'_>, key: Key, position: Point) -> usize;
+
+    /// Returns the message for the child element with the given key dragged to a new drop
+    /// location, if any.
+    fn drag_message(&self, _key: Key, _drop_location: usize) -> Option<Message> {
+        None
+    }
+
+    /// Returns the message for the child element with the given key dropped at the drop
+    /// location, if any.
+    fn drop_message(&self, _key: Key, _drop_location: usize) -> Option<Message> {
+        None
+    }
+
+    /// Returns the message for the cancelled drag of the child element with the given key,
+    /// if any.
+    fn cancel_message(&self, _key: Key) -> Option<Message> {
+        None
+    }
+
+    /// Returns how far a grabbed child element has to be moved before it is dragged.
+    fn drag_threshold(&self) -> f32 {
+        0.0
+    }
+
+    /// Returns the [`Hold`] delaying the drag of the child elements grabbed with the mouse,
+    /// if any.
+    fn hold(&self) -> Option<Hold> {
+        None
+    }
+
+    /// Returns the given drag position restricted for a drag from the `origin`.
+    fn constrain(&self, position: Point, _origin: Point) -> Point {
+        position
+    }
+
+    /// Grabs the child element with the given key and bounds by the press `event` at the
+    /// given position.
+    fn grab(
+        &self,
+        state: &mut Self::State,
+        _shell: &mut Shell<'_, Message>,
+        _layout: Layout<'_>,
+        event: &Event,
+        item: (Key, Rectangle),
+        position: Point,
+    ) {
+        *self.drag(state) = grabbed(event, item, position, self.hold());
+    }
+
+    /// Moves the grabbed or dragged child element to the given position, producing the
+    /// [`Gesture::drag_message`] if its drop location changes.
+    fn drag_to(
+        &self,
+        state: &mut Self::State,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        position: Point,
+    ) {
+        let drag = self.drag(state);
+        let (DragState::Grabbed {
+            key,
+            origin,
+            offset,
+            ..
+        }
+        | DragState::Dragged {
+            key,
+            origin,
+            offset,
+            ..
+        }) = *drag
+        else {
+            return;
+        };
+        if Some(position) == drag.last_position() {
+            return;
+        }
+        let drop_location = self.drop_location(layout, key, position);
+        if Some(drop_location) != drag.drop_location() {
+            if let Some(message) = self.drag_message(key, drop_location) {
+                shell.publish(message);
+            }
+        }
+        *drag = DragState::Dragged {
+            key,
+            origin,
+            offset,
+            position,
+            drop_location,
+        };
+        shell.request_redraw();
+    }
+
+    /// Moves the grabbed or dragged child element with the pointer at the given position.
+    fn drag_move(
+        &self,
+        state: &mut Self::State,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        position: Point,
+    ) {
+        if let DragState::Grabbed { origin, .. } | DragState::Dragged { origin, .. } =
+            *self.drag(state)
+        {
+            self.drag_to(state, shell, layout, self.constrain(position, origin));
+        }
+    }
+
+    /// Drops the dragged child element at the given position, producing the
+    /// [`Gesture::drop_message`].
+    fn drop(
+        &self,
+        state: &mut Self::State,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        position: Point,
+    ) {
+        let drag = self.drag(state);
+        let Some(key) = drag.key() else {
+            return;
+        };
+        *drag = DragState::Idle;
+        if let Some(message) = self.drop_message(key, self.drop_location(layout, key, position)) {
+            shell.publish(message);
+        }
+        shell.request_redraw();
+        shell.capture_event();
+    }
+
+    /// Releases the dragged child element, dropping it at its drag position.
+    fn release_drag(
+        &self,
+        state: &mut Self::State,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        if let DragState::Dragged { position, .. } = *self.drag(state) {
+            self.drop(state, shell, layout, position);
+        }
+    }
+
+    /// Releases the grabbed child element before it has been dragged, producing the
+    /// [`Gesture::cancel_message`] unless another widget handled the release.
+    fn release_grab(
+        &self,
+        state: &mut Self::State,
+        shell: &mut Shell<'_, Message>,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let drag = self.drag(state);
+        if let Some(key) = drag.key().filter(|_| !shell.is_event_captured()) {
+            if let Some(message) = self.cancel_message(key) {
+                shell.publish(message);
+            }
+            shell.capture_event();
+        }
+        *drag = DragState::Idle;
+    }
+
+    /// Reacts to the cancelled drag of the child element with the given key, which was at
+    /// the `drop_location`.
+    fn cancelled(
+        &self,
+        _state: &mut Self::State,
+        _shell: &mut Shell<'_, Message>,
+        _layout: Layout<'_>,
+        _key: Key,
+        _drop_location: Option<usize>,
+    ) {
+    }
+}
+
+/// The steps of moving a child element picked up without holding a button a reorderable
+/// widget adapts, driven by [`update_picked_up`].
+pub(crate) trait PickUp<Key, Message>: Gesture<Key, Message>
+where
+    Key: Copy + PartialEq,
+{
+    /// Returns whether the dragged child element in the given widget state has been picked
+    /// up without holding a button.
+    fn picked_up<'s>(&self, state: &'s mut Self::State) -> &'s mut bool;
+
+    /// Returns the key of the focused child element in the given widget state, if any.
+    fn focused(&self, state: &Self::State) -> Option<Key>;
+
+    /// Returns the number of child elements the picked up child element moves among.
+    fn len(&self) -> usize;
+
+    /// Returns whether the arrow keys wrap the picked up child element around the ends.
+    fn keyboard_wrap(&self) -> bool {
+        false
+    }
+
+    /// Picks up the child element with the given key, returning whether it has been found.
+    fn pick_up(
+        &self,
+        state: &mut Self::State,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        key: Key,
+    ) -> bool;
+
+    /// Returns the drag position moving the picked up child element at the `position` to
+    /// the drop location `slot` along the `axis`.
+    fn slot_position(
+        &self,
+        axis: &Axis,
+        layout: Layout<'_>,
+        slot: usize,
+        position: Point,
+    ) -> Point {
+        slot_position(axis, &layout, slot, position)
+    }
+
+    /// Reveals the drop location of the picked up child element in the `viewport` after
+    /// moving it with the keyboard.
+    fn reveal(
+        &self,
+        _state: &mut Self::State,
+        _shell: &mut Shell<'_, Message>,
+        _layout: Layout<'_>,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+/// Updates the [`DragState`] of a reorderable widget with the drag gesture: press to grab
+/// a child element, move to drag it and release to drop it, with the steps of the given
+/// [`Gesture`].
+///
+/// A grabbed child element is only dragged once it has been moved further than the
+/// [`Gesture::drag_threshold`]. With a [`Gesture::hold`], mouse presses have to be held
+/// for its delay before the grabbed child element can be moved; moving it earlier releases
+/// the grab. The drag is cancelled by the events [`cancel`] handles.
+pub(crate) fn update<Key, Message, G>(
+    gesture: &mut G,
+    state: &mut G::State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+) where
+    Key: Copy + PartialEq,
+    G: Gesture<Key, Message>,
+{
+    let drag = *gesture.drag(state);
+
+    // Widgets handling the events later must not react to the pointer while dragging
+    if matches!(drag, DragState::Dragged { .. })
+        && matches!(
+            event,
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Mouse(mouse::Event::ButtonReleased(_))
+                | Event::Touch(touch::Event::FingerMoved { .. })
+                | Event::Touch(touch::Event::FingerLifted { .. })
+        )
+    {
+        shell.capture_event();
+    }
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if shell.is_event_captured() {
+                return;
+            }
+            let Some(position) = cursor.position_over(layout.bounds()) else {
+                return;
+            };
+            if let Some(item) = gesture.grab_at(state, shell, layout, event, position) {
+                gesture.grab(state, shell, layout, event, item, position);
+                shell.request_redraw();
+                shell.capture_event();
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+        | Event::Touch(touch::Event::FingerLost { .. })
+        | Event::Keyboard(keyboard::Event::KeyPressed { .. })
+        | Event::Window(window::Event::Unfocused) => {
+            let drop_location = drag.drop_location();
+            if let Some(key) = cancel(gesture.drag(state), event, shell, |key| {
+                gesture.cancel_message(key)
+            }) {
+                gesture.cancelled(state, shell, layout, key, drop_location);
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. }) => match drag {
+            DragState::Grabbed { .. } => gesture.release_grab(state, shell, layout, cursor),
+            DragState::Dragged { .. } => gesture.release_drag(state, shell, layout, cursor),
+            DragState::Idle => {}
+        },
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. }) => {
+            let (DragState::Grabbed { key, origin, .. } | DragState::Dragged { key, origin, .. }) =
+                drag
+            else {
+                return;
+            };
+            let is_grabbed = matches!(drag, DragState::Grabbed { .. });
+            if is_grabbed && shell.is_event_captured() {
+                // Another widget consumed the gesture, e.g. a scrollable started scrolling,
+                // so the grab is released without any messages
+                *gesture.drag(state) = DragState::Idle;
+                shell.request_redraw();
+                return;
+            }
+            let Some(position) = cursor
+                .position()
+                .filter(|position| Some(*position) != drag.last_position())
+            else {
+                return;
+            };
+            let below_threshold =
+                is_grabbed && position.distance(origin) < gesture.drag_threshold();
+            let is_holding = matches!(
+                drag,
+                DragState::Grabbed {
+                    hold_until: Some(hold_until),
+                    ..
+                } if gesture.hold().is_some_and(|hold| hold.now < hold_until)
+            );
+            if below_threshold {
+                return;
+            }
+            if is_holding {
+                // Moved before the delay elapsed, so the gesture is not a drag
+                *gesture.drag(state) = DragState::Idle;
+                if let Some(message) = gesture.cancel_message(key) {
+                    shell.publish(message);
+                }
+                shell.request_redraw();
+            } else {
+                shell.capture_event();
+                gesture.drag_move(state, shell, layout, position);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Updates a child element of a reorderable widget picked up without holding a button,
+/// with the steps of the given [`PickUp`]: Space picks up the focused child element, the
+/// arrow keys along the `axis` move it by one drop location and Enter or a press drops it.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_picked_up<Key, Message, G>(
+    axis: &Axis,
+    gesture: &G,
+    state: &mut G::State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+    viewport: &Rectangle,
+) where
+    Key: Copy + PartialEq,
+    G: PickUp<Key, Message>,
+{
+    use keyboard::key::Named;
+
+    let drag = *gesture.drag(state);
+    let DragState::Dragged {
+        origin,
+        position,
+        drop_location,
+        ..
+    } = drag
+    else {
+        *gesture.picked_up(state) = false;
+        if let (
+            Some(key),
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(Named::Space),
+                ..
+            }),
+        ) = (gesture.focused(state).filter(|_| drag.is_idle()), event)
+        {
+            if gesture.pick_up(state, shell, layout, key) {
+                *gesture.picked_up(state) = true;
+                shell.request_redraw();
+                shell.capture_event();
+            }
+        }
+        return;
+    };
+    if !*gesture.picked_up(state) {
+        return;
+    }
+
+    let position = match event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            ..
+        }) => {
+            let slot = keyboard_slot(
+                axis,
+                *named,
+                drop_location,
+                gesture.len(),
+                gesture.keyboard_wrap(),
+            );
+            if let Some(slot) = slot {
+                let position = gesture.slot_position(axis, layout, slot, position);
+                gesture.drag_to(state, shell, layout, position);
+                gesture.reveal(state, shell, layout, viewport);
+                shell.request_redraw();
+                shell.capture_event();
+                return;
+            } else if *named != Named::Enter {
+                return;
+            }
+            position
+        }
+        // The press drops the child element where it has been moved with the pointer
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => cursor
+            .position()
+            .map(|position| gesture.constrain(position, origin))
+            .unwrap_or(position),
+        _ => return,
+    };
+    gesture.drop(state, shell, layout, position);
+    *gesture.picked_up(state) = false;
+    shell.request_redraw();
+    shell.capture_event();
+}
+
+/// Returns the [`DragState`] of the child element with the given key and bounds grabbed
+/// by the press `event` at the given position, holding mouse presses with the `hold`.
+pub(crate) fn grabbed<Key>(
+    event: &Event,
+    (key, bounds): (Key, Rectangle),
+    position: Point,
+    hold: Option<Hold>,
+) -> DragState<Key>
+where
+    Key: Copy + PartialEq,
+{
+    DragState::Grabbed {
+        key,
+        origin: position,
+        offset: position - bounds.position(),
+        hold_until: hold
+            .filter(|_| matches!(event, Event::Mouse(_)))
+            .map(|hold| hold.now + hold.delay),
+    }
+}
+
+/// Returns the key and the bounds of the child element at the given position among the
+/// keyed layouts of the children, if any.
+pub(crate) fn child_at<Key>(
+    keys: &[Key],
+    layout: Layout<'_>,
+    position: Point,
+) -> Option<(Key, Rectangle)>
+where
+    Key: Copy,
+{
+    keys.iter()
+        .zip(layout.children())
+        .map(|(key, item_layout)| (*key, item_layout.bounds()))
+        .find(|(_, bounds)| bounds.contains(position))
+}
+
+/// Picks up the child element with the given key of a reorderable widget, as if it was
+/// dragged from its center, returning whether it has been found.
+pub(crate) fn pick_up<Key>(
+    drag: &mut DragState<Key>,
+    layout: &Layout,
+    keys: &[Key],
+    key: Key,
+    drop_location: impl Fn(Point) -> usize,
+) -> bool
+where
+    Key: Copy + PartialEq,
+{
+    let Some((_, item_layout)) = keys
+        .iter()
+        .zip(layout.children())
+        .find(|(item_key, _)| **item_key == key)
+    else {
+        return false;
+    };
+    let bounds = item_layout.bounds();
+    let origin = bounds.center();
+
+    *drag = DragState::Dragged {
+        key,
+        origin,
+        offset: origin - bounds.position(),
+        position: origin,
+        drop_location: drop_location(origin),
+    };
+
+    true
+}
+
+/// Returns the given drag position moved along the `axis` to the drop location `slot`,
+/// for moving a picked up child element with the keyboard.
+pub(crate) fn slot_position(axis: &Axis, layout: &Layout, slot: usize, position: Point) -> Point {
+    let slot = slot_bounds(
+        axis,
+        layout.children().map(|item_layout| item_layout.bounds()),
+        Rectangle::new(position, Size::ZERO),
+        slot,
+    );
+
+    slot.position()
+}
+
+/// Scrolls a reorderable widget while a child element is dragged near the edges of the
+/// `viewport` along the `axis`, by producing the `on_auto_scroll` message on every frame.
+///
+/// The dragged child element follows the content as it scrolls under the cursor, with the
+/// steps of the given [`Gesture`].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn auto_scroll<Key, Message, G>(
+    axis: &Axis,
+    gesture: &G,
+    state: &mut G::State,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+    viewport: &Rectangle,
+    on_auto_scroll: &dyn Fn(Vector) -> Message,
+) where
+    Key: Copy + PartialEq,
+    G: Gesture<Key, Message>,
+{
+    let DragState::Dragged { origin, .. } = *gesture.drag(state) else {
+        return;
+    };
+    let Some((position, delta)) = cursor.position().and_then(|position| {
+        auto_scroll_delta(axis, layout.bounds(), viewport, position).map(|delta| (position, delta))
+    }) else {
+        return;
+    };
+    if let Event::Window(window::Event::RedrawRequested(_)) = event {
+        shell.publish(on_auto_scroll(delta));
+        // The content scrolls under the cursor, so the drop location moves
+        gesture.drag_to(state, shell, layout, gesture.constrain(position, origin));
+    }
+    // Keep scrolling on the next frames
+    shell.request_redraw();
+}
+
+/// Returns whether the given position is close enough to the edges of the `bounds` along
+/// the `axis` for a drag to scroll them.
+pub(crate) fn is_near_edge(axis: &Axis, bounds: Rectangle, position: Point) -> bool {
+    let start = main(axis, bounds.position());
+    let end = main(
+        axis,
+        bounds.position() + Vector::new(bounds.width, bounds.height),
+    );
+    let main = main(axis, position);
+    let zone = scroll_zone(axis, &bounds);
+
+    main < start + zone || main > end - zone
+}
+
+/// Returns the extent of the zones at the edges of the `viewport` along the `axis` within
+/// which dragging scrolls it, shrunk to half of the viewport so that they do not overlap.
+fn scroll_zone(axis: &Axis, viewport: &Rectangle) -> f32 {
+    let length = match axis {
+        Axis::Horizontal => viewport.width,
+        Axis::Vertical => viewport.height,
+    };
+
+    AUTO_SCROLL_ZONE.min(length * 0.5)
+}
+
+/// Cancels the drag of a reorderable widget on the events meant to abort it: a right
+/// click, a lost touch, Escape, or the window losing focus, since the release would never
+/// arrive while it is unfocused.
+///
+/// Returns the key of the child element whose drag was cancelled, if any.
+fn cancel<Key, Message>(
+    drag: &mut DragState<Key>,
+    event: &Event,
+    shell: &mut Shell<'_, Message>,
+    cancel_message: impl FnOnce(Key) -> Option<Message>,
+) -> Option<Key>
+where
+    Key: Copy + PartialEq,
+{
+    let is_unfocused = match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+        | Event::Touch(touch::Event::FingerLost { .. })
+        | Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Escape),
+            ..
+        }) => false,
+        Event::Window(window::Event::Unfocused) => true,
+        _ => return None,
+    };
+    let key = drag.key()?;
+
+    *drag = DragState::Idle;
+    if let Some(message) = cancel_message(key) {
+        shell.publish(message);
+    }
+    shell.request_redraw();
+    // Other widgets should learn about the focus loss as well
+    if !is_unfocused {
+        shell.capture_event();
+    }
+
+    Some(key)
+}
+
+/// Returns the coordinate of the given point along the `axis`.
+pub(crate) fn main(axis: &Axis, point: Point) -> f32 {
+    match axis {
+        Axis::Horizontal => point.x,
+        Axis::Vertical => point.y,
+    }
+}
+
+/// Returns the coordinate of the center of the given bounds along the `axis`.
+pub(crate) fn main_center(axis: &Axis, bounds: Rectangle) -> f32 {
+    main(axis, bounds.center())
+}
+
+/// Returns the distance from the given position to the given bounds along the `axis`, which
+/// is zero within the bounds.
+pub(crate) fn main_distance(axis: &Axis, bounds: Rectangle, position: Point) -> f32 {
+    let (start, length) = match axis {
+        Axis::Horizontal => (bounds.x, bounds.width),
+        Axis::Vertical => (bounds.y, bounds.height),
+    };
+    let main = main(axis, position);
+
+    (start - main).max(main - (start + length)).max(0.0)
+}
+
+/// Returns the given position with its coordinate across the `axis` locked to the `origin`.
+pub(crate) fn lock_cross(axis: &Axis, position: Point, origin: Point) -> Point {
+    match axis {
+        Axis::Horizontal => Point::new(position.x, origin.y),
+        Axis::Vertical => Point::new(origin.x, position.y),
+    }
+}
+
+/// Returns the amount to scroll the `viewport` by while dragging at the given `position`
+/// near its edges along the `axis`, if the widget with the given `bounds` extends beyond
+/// that edge.
+pub(crate) fn auto_scroll_delta(
+    axis: &Axis,
+    bounds: Rectangle,
+    viewport: &Rectangle,
+    position: Point,
+) -> Option<Vector> {
+    let main_start = |bounds: &Rectangle| main(axis, bounds.position());
+    let main_end = |bounds: &Rectangle| {
+        main(
+            axis,
+            bounds.position() + Vector::new(bounds.width, bounds.height),
+        )
+    };
+    let zone = scroll_zone(axis, viewport);
+    let speed = |depth: f32| AUTO_SCROLL_SPEED * (depth / zone).clamp(0.0, 1.0);
+
+    let start_depth = main_start(viewport) + zone - main(axis, position);
+    let end_depth = main(axis, position) - (main_end(viewport) - zone);
+    let amount = if start_depth > 0.0 && main_start(&bounds) < main_start(viewport) {
+        -speed(start_depth)
+    } else if end_depth > 0.0 && main_end(&bounds) > main_end(viewport) {
+        speed(end_depth)
+    } else {
+        return None;
+    };
+
+    Some(along(axis, amount))
+}
+
+/// Returns the amount to scroll the `viewport` by along the `axis` so that the given
+/// `target` bounds become visible, if they are not.
+pub(crate) fn reveal_delta(axis: &Axis, target: Rectangle, viewport: &Rectangle) -> Option<Vector> {
+    let end = |bounds: &Rectangle| {
+        main(
+            axis,
+            bounds.position() + Vector::new(bounds.width, bounds.height),
+        )
+    };
+    let before = main(axis, target.position()) - main(axis, viewport.position());
+    let after = end(&target) - end(viewport);
+    let amount = if before < 0.0 {
+        before
+    } else if after > 0.0 {
+        after.min(before)
+    } else {
+        return None;
+    };
+
+    Some(along(axis, amount))
+}
+
+/// Returns the bounds the dragged child element with the given bounds occupies at the drop
+/// location `slot` among the children with the given bounds along the `axis`.
+pub(crate) fn slot_bounds(
+    axis: &Axis,
+    children: impl IntoIterator<Item = Rectangle>,
+    dragged: Rectangle,
+    slot: usize,
+) -> Rectangle {
+    let mut start = main(axis, dragged.position());
+    for (index, bounds) in children.into_iter().enumerate() {
+        if index == slot {
+            start = main(axis, bounds.position());
+            break;
+        }
+        // Past the last child element, the slot starts at its end
+        start = main(
+            axis,
+            bounds.position() + Vector::new(bounds.width, bounds.height),
+        );
+    }
+
+    match axis {
+        Axis::Horizontal => Rectangle {
+            x: start,
+            ..dragged
+        },
+        Axis::Vertical => Rectangle {
+            y: start,
+            ..dragged
+        },
+    }
+}
+
+/// Returns a vector of the given length along the `axis`.
+fn along(axis: &Axis, amount: f32) -> Vector {
+    match axis {
+        Axis::Horizontal => Vector::new(amount, 0.0),
+        Axis::Vertical => Vector::new(0.0, amount),
+    }
+}
+
+/// Returns the drop location a picked up child element moves to when the given arrow key
+/// is pressed at the `drop_location`, among the `len + 1` drop locations of the `axis`.
+///
+/// With `wrap`, moving past either end continues at the other one.
+pub(crate) fn keyboard_slot(
+    axis: &Axis,
+    key: keyboard::key::Named,
+    drop_location: usize,
+    len: usize,
+    wrap: bool,
+) -> Option<usize> {
+    use keyboard::key::Named;
+
+    let forward = match (axis, key) {
+        (Axis::Horizontal, Named::ArrowLeft) | (Axis::Vertical, Named::ArrowUp) => false,
+        (Axis::Horizontal, Named::ArrowRight) | (Axis::Vertical, Named::ArrowDown) => true,
+        _ => return None,
+    };
+
+    Some(match (forward, wrap) {
+        (false, true) if drop_location == 0 => len,
+        (false, _) => drop_location.saturating_sub(1),
+        (true, true) if drop_location >= len => 0,
+        (true, _) => (drop_location + 1).min(len),
+    })
+}
+
+/// Returns the index of the drop location among the children of a reorderable widget
+/// at given `position`.
+pub(crate) fn drop_location(axis: &Axis, layout: &Layout, position: Point) -> usize {
+    let mut index = 0;
+    for item_layout in layout.children() {
+        if main(axis, position) < main_center(axis, item_layout.bounds()) {
+            break;
+        }
+        index += 1;
+    }
+    index
+}
+
+/// Returns the zone of a child element with the given bounds where dropping targets the
+/// child element itself instead of a drop location next to it: the middle half of the
+/// bounds along the `axis`.
+fn onto_zone(axis: &Axis, bounds: Rectangle) -> Rectangle {
+    match axis {
+        Axis::Horizontal => Rectangle {
+            x: bounds.x + bounds.width * 0.25,
+            width: bounds.width * 0.5,
+            ..bounds
+        },
+        Axis::Vertical => Rectangle {
+            y: bounds.y + bounds.height * 0.25,
+            height: bounds.height * 0.5,
+            ..bounds
+        },
+    }
+}
+
+/// Returns the index of the child element the dragged child element with the given key
+/// targets at the given `position`, i.e. the child element whose [`onto_zone`] contains
+/// the position, if it is not the dragged child element and `is_target` accepts it.
+pub(crate) fn drop_target<Key>(
+    axis: &Axis,
+    layout: &Layout,
+    keys: &[Key],
+    key: Key,
+    position: Point,
+    is_target: impl Fn(usize) -> bool,
+) -> Option<usize>
+where
+    Key: Copy + PartialEq,
+{
+    layout
+        .children()
+        .zip(keys)
+        .enumerate()
+        .find(|(index, (item_layout, item_key))| {
+            **item_key != key
+                && onto_zone(axis, item_layout.bounds()).contains(position)
+                && is_target(*index)
+        })
+        .map(|(index, _)| index)
+}
+
+/// Returns the index of the gap among the children of a reorderable widget at given
+/// `position`, if the position is not over any child element.
+pub(crate) fn gap_at(axis: &Axis, layout: &Layout, position: Point) -> Option<usize> {
+    layout
+        .children()
+        .all(|item_layout| !item_layout.bounds().contains(position))
+        .then(|| drop_location(axis, layout, position))
+}
+
+/// Returns the position of the drop location marker along the `axis`.
+fn marker_position(
+    axis: &Axis,
+    layout: &Layout,
+    spacing: f32,
+    drop_location: usize,
+) -> Option<f32> {
+    let end = |bounds: Rectangle| match axis {
+        Axis::Horizontal => bounds.x + bounds.width,
+        Axis::Vertical => bounds.y + bounds.height,
+    };
+
+    if layout.children().count() == 0 {
+        None
+    } else if drop_location < layout.children().count() {
+        let child_bounds_after = layout.children().nth(drop_location).unwrap().bounds();
+        Some(main(axis, child_bounds_after.position()) - spacing * 0.5)
+    } else {
+        let last_child_bounds = layout.children().last().unwrap().bounds();
+        Some(end(last_child_bounds) + spacing * 0.5)
+    }
+}
+
+/// Returns the bounds of the drop location marker line for the given drop location.
+pub(crate) fn marker_bounds(
+    axis: &Axis,
+    layout: &Layout,
+    padding: Padding,
+    spacing: f32,
+    drop_location: usize,
+) -> Option<Rectangle> {
+    let position = marker_position(axis, layout, spacing, drop_location)?;
+    let bounds = layout.bounds();
+
+    // Span the child elements around the drop location, which may be narrower than the
+    // content of the widget and aligned inside of it
+    let (start, end) = layout
+        .children()
+        .skip(drop_location.saturating_sub(1))
+        .take(if drop_location == 0 { 1 } else { 2 })
+        .map(|child_layout| {
+            let child_bounds = child_layout.bounds();
+            match axis {
+                Axis::Horizontal => (child_bounds.y, child_bounds.y + child_bounds.height),
+                Axis::Vertical => (child_bounds.x, child_bounds.x + child_bounds.width),
+            }
+        })
+        .reduce(|(start, end), (child_start, child_end)| {
+            (start.min(child_start), end.max(child_end))
+        })?;
+    let (content_start, content_end) = match axis {
+        Axis::Horizontal => (
+            bounds.y + padding.top,
+            bounds.y + bounds.height - padding.bottom,
+        ),
+        Axis::Vertical => (
+            bounds.x + padding.left,
+            bounds.x + bounds.width - padding.right,
+        ),
+    };
+    let start = start.max(content_start);
+    let length = (end.min(content_end) - start).max(0.0);
+
+    Some(match axis {
+        Axis::Horizontal => Rectangle {
+            x: position - MARKER_LINE_WIDTH * 0.5,
+            y: start,
+            width: MARKER_LINE_WIDTH,
+            height: length,
+        },
+        Axis::Vertical => Rectangle {
+            x: start,
+            y: position - MARKER_LINE_WIDTH * 0.5,
+            width: length,
+            height: MARKER_LINE_WIDTH,
+        },
+    })
+}
+
+/// Moves the drop location marker with the given bounds along the `axis` inside the
+/// `viewport`, if the marker is partially cut off at the edges of the viewport, keeping
+/// a margin of `radius` around its center.
+fn nudge_marker(axis: &Axis, bounds: Rectangle, viewport: &Rectangle, radius: f32) -> Rectangle {
+    let center = main_center(axis, bounds);
+    let (viewport_start, viewport_length) = match axis {
+        Axis::Horizontal => (viewport.x, viewport.width),
+        Axis::Vertical => (viewport.y, viewport.height),
+    };
+    let start = viewport_start + radius;
+    let end = viewport_start + viewport_length - radius;
+
+    let nudged_center = if (viewport_start - radius..start).contains(&center) {
+        start
+    } else if (end..viewport_start + viewport_length + radius).contains(&center) {
+        end
+    } else {
+        center
+    };
+
+    match axis {
+        Axis::Horizontal => Rectangle {
+            x: nudged_center - bounds.width * 0.5,
+            ..bounds
+        },
+        Axis::Vertical => Rectangle {
+            y: nudged_center - bounds.height * 0.5,
+            ..bounds
+        },
+    }
+}
+
+/// Splits the drop location marker line with the given bounds into dashes along its
+/// length, which runs across the `axis`.
+fn dashes(axis: &Axis, bounds: Rectangle) -> impl Iterator<Item = Rectangle> {
+    let (start, length) = match axis {
+        Axis::Horizontal => (bounds.y, bounds.height),
+        Axis::Vertical => (bounds.x, bounds.width),
+    };
+    let period = MARKER_DASH_LENGTH + MARKER_DASH_GAP;
+    let count = (length / period).ceil().max(0.0) as usize;
+    let is_horizontal = matches!(axis, Axis::Horizontal);
+
+    (0..count).map(move |dash| {
+        let offset = dash as f32 * period;
+        let dash_length = MARKER_DASH_LENGTH.min(length - offset);
+        if is_horizontal {
+            Rectangle {
+                y: start + offset,
+                height: dash_length,
+                ..bounds
+            }
+        } else {
+            Rectangle {
+                x: start + offset,
+                width: dash_length,
+                ..bounds
+            }
+        }
+    })
+}
+
+/// Draws the drop location marker with the given bounds: a line with a circle at its start,
+/// shaped by the [`MarkerStyle`] of the `style`.
+pub(crate) fn draw_marker<Renderer>(
+    renderer: &mut Renderer,
+    axis: &Axis,
+    bounds: Rectangle,
+    viewport: &Rectangle,
+    style: Style,
+) where
+    Renderer: iced::advanced::Renderer,
+{
+    let color = style.color;
+    let MarkerStyle {
+        thickness,
+        radius,
+        start_inset,
+        end_inset,
+        end_cap,
+        cap_radius,
+    } = style.marker;
+    let circle_outer_radius = if end_cap { cap_radius } else { 0.0 };
+    let circle_inner_radius = (circle_outer_radius - thickness).max(0.0);
+    let bounds = nudge_marker(
+        axis,
+        bounds,
+        viewport,
+        circle_outer_radius.max(thickness * 0.5),
+    );
+
+    // Apply the thickness around the center and the insets at the ends of the line
+    let center = main_center(axis, bounds);
+    let bounds = match axis {
+        Axis::Horizontal => Rectangle {
+            x: center - thickness * 0.5,
+            y: bounds.y + start_inset,
+            width: thickness,
+            height: (bounds.height - start_inset - end_inset).max(0.0),
+        },
+        Axis::Vertical => Rectangle {
+            x: bounds.x + start_inset,
+            y: center - thickness * 0.5,
+            width: (bounds.width - start_inset - end_inset).max(0.0),
+            height: thickness,
+        },
+    };
+
+    // Draw line
+    let line_bounds = match axis {
+        Axis::Horizontal => Rectangle {
+            y: bounds.y + circle_inner_radius,
+            height: (bounds.height - circle_inner_radius).max(0.0),
+            ..bounds
+        },
+        Axis::Vertical => Rectangle {
+            x: bounds.x + circle_inner_radius,
+            width: (bounds.width - circle_inner_radius).max(0.0),
+            ..bounds
+        },
+    };
+    let line_border = Border {
+        radius: Radius::new(radius),
+        ..Border::default()
+    };
+    if style.dashed {
+        for dash_bounds in dashes(axis, line_bounds) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: dash_bounds,
+                    border: line_border,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    } else {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: line_bounds,
+                border: line_border,
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+
+    if !end_cap {
+        return;
+    }
+
+    // Draw circle at the start of the line
+    let circle_center = match axis {
+        Axis::Horizontal => Point::new(bounds.center_x(), bounds.y),
+        Axis::Vertical => Point::new(bounds.x, bounds.center_y()),
+    };
+    let circle_bounds = Rectangle {
+        x: circle_center.x - circle_outer_radius,
+        y: circle_center.y - circle_outer_radius,
+        width: circle_outer_radius * 2.0,
+        height: circle_outer_radius * 2.0,
+    };
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: circle_bounds,
+            border: Border {
+                radius: Radius::new(circle_outer_radius),
+                color,
+                width: thickness,
+            },
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced::advanced::layout::Node;
+    use iced::Size;
+
+    /// The length of the test children along the main axis.
+    const LENGTH: f32 = 20.0;
+    /// The spacing between the test children.
+    const SPACING: f32 = 10.0;
+
+    /// Returns the layout node of a widget at the origin holding `count` children of
+    /// 20 x 100 along the `axis`, spaced by 10.
+    fn widget(axis: &Axis, count: usize) -> Node {
+        let size = |main: f32, cross: f32| match axis {
+            Axis::Horizontal => Size::new(main, cross),
+            Axis::Vertical => Size::new(cross, main),
+        };
+        let children = (0..count)
+            .map(|index| {
+                let start = index as f32 * (LENGTH + SPACING);
+                let position = match axis {
+                    Axis::Horizontal => Point::new(start, 0.0),
+                    Axis::Vertical => Point::new(0.0, start),
+                };
+
+                Node::new(size(LENGTH, 100.0)).move_to(position)
+            })
+            .collect();
+
+        Node::with_children(size(100.0, 100.0), children)
+    }
+
+    /// Returns the point at `main` along the `axis` and 50 across it.
+    fn at(axis: &Axis, main: f32) -> Point {
+        match axis {
+            Axis::Horizontal => Point::new(main, 50.0),
+            Axis::Vertical => Point::new(50.0, main),
+        }
+    }
+
+    #[test]
+    fn drop_location_before_after_and_between_children() {
+        for axis in [Axis::Horizontal, Axis::Vertical] {
+            let node = widget(&axis, 3);
+            let layout = Layout::new(&node);
+            let drop_location = |main| drop_location(&axis, &layout, at(&axis, main));
+
+            assert_eq!(drop_location(-5.0), 0);
+            assert_eq!(drop_location(5.0), 0);
+            assert_eq!(drop_location(15.0), 1);
+            // In the spacing between the first and the second child element
+            assert_eq!(drop_location(25.0), 1);
+            assert_eq!(drop_location(45.0), 2);
+            assert_eq!(drop_location(75.0), 3);
+            assert_eq!(drop_location(200.0), 3);
+        }
+    }
+
+    #[test]
+    fn gap_at_outside_of_children() {
+        for axis in [Axis::Horizontal, Axis::Vertical] {
+            let node = widget(&axis, 3);
+            let layout = Layout::new(&node);
+            let gap_at = |main| gap_at(&axis, &layout, at(&axis, main));
+
+            assert_eq!(gap_at(5.0), None);
+            assert_eq!(gap_at(25.0), Some(1));
+            assert_eq!(gap_at(55.0), Some(2));
+            assert_eq!(gap_at(90.0), Some(3));
+        }
+    }
+
+    #[test]
+    fn marker_bounds_in_the_middle_of_the_spacing() {
+        let node = widget(&Axis::Vertical, 3);
+        let layout = Layout::new(&node);
+        let marker = |drop_location| {
+            marker_bounds(
+                &Axis::Vertical,
+                &layout,
+                Padding::ZERO,
+                SPACING,
+                drop_location,
+            )
+        };
+
+        assert_eq!(
+            marker(0),
+            Some(Rectangle {
+                x: 0.0,
+                y: -SPACING * 0.5 - MARKER_LINE_WIDTH * 0.5,
+                width: 100.0,
+                height: MARKER_LINE_WIDTH,
+            })
+        );
+        assert_eq!(marker(1).map(|bounds| bounds.y), Some(24.0));
+        // After the last child element, ending at 80
+        assert_eq!(marker(3).map(|bounds| bounds.y), Some(84.0));
+    }
+
+    #[test]
+    fn marker_bounds_across_the_axis() {
+        let node = widget(&Axis::Horizontal, 2);
+        let layout = Layout::new(&node);
+        let padding = Padding {
+            top: 10.0,
+            ..Padding::ZERO
+        };
+
+        assert_eq!(
+            marker_bounds(&Axis::Horizontal, &layout, padding, SPACING, 1),
+            Some(Rectangle {
+                x: 24.0,
+                y: 10.0,
+                width: MARKER_LINE_WIDTH,
+                height: 90.0,
+            })
+        );
+    }
+
+    #[test]
+    fn marker_bounds_without_children() {
+        let node = widget(&Axis::Vertical, 0);
+        let layout = Layout::new(&node);
+
+        assert_eq!(
+            marker_bounds(&Axis::Vertical, &layout, Padding::ZERO, SPACING, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn nudge_marker_inside_the_viewport() {
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let marker = |y: f32| Rectangle::new(Point::new(0.0, y - 1.0), Size::new(100.0, 2.0));
+        let nudged_y = |y| nudge_marker(&Axis::Vertical, marker(y), &viewport, 4.0).y + 1.0;
+
+        assert_eq!(nudged_y(0.0), 4.0);
+        assert_eq!(nudged_y(-2.0), 4.0);
+        assert_eq!(nudged_y(50.0), 50.0);
+        assert_eq!(nudged_y(100.0), 96.0);
+        // Far outside of the viewport, the marker is left alone
+        assert_eq!(nudged_y(-10.0), -10.0);
+        assert_eq!(nudged_y(110.0), 110.0);
+    }
+
+    #[test]
+    fn dashes_along_the_marker() {
+        let bounds = Rectangle::new(Point::new(0.0, 5.0), Size::new(25.0, 2.0));
+        let vertical: Vec<_> = dashes(&Axis::Vertical, bounds)
+            .map(|dash| (dash.x, dash.width))
+            .collect();
+
+        assert_eq!(vertical, [(0.0, 6.0), (10.0, 6.0), (20.0, 5.0)]);
+
+        let bounds = Rectangle::new(Point::new(5.0, 0.0), Size::new(2.0, 12.0));
+        let horizontal: Vec<_> = dashes(&Axis::Horizontal, bounds)
+            .map(|dash| (dash.y, dash.height))
+            .collect();
+
+        assert_eq!(horizontal, [(0.0, 6.0), (10.0, 2.0)]);
+    }
+
+    #[test]
+    fn auto_scroll_delta_near_the_edges() {
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let bounds = Rectangle::new(Point::new(-50.0, -50.0), Size::new(300.0, 300.0));
+
+        for axis in [Axis::Horizontal, Axis::Vertical] {
+            let delta = |main| auto_scroll_delta(&axis, bounds, &viewport, at(&axis, main));
+
+            assert_eq!(delta(50.0), None);
+            // The zone spans the whole AUTO_SCROLL_ZONE at each edge
+            assert_eq!(delta(45.0), None);
+            assert_eq!(delta(25.0), Some(along(&axis, -6.0)));
+            assert_eq!(delta(5.0), Some(along(&axis, -14.0)));
+            assert_eq!(delta(95.0), Some(along(&axis, 14.0)));
+            assert_eq!(delta(-10.0), Some(along(&axis, -AUTO_SCROLL_SPEED)));
+        }
+    }
+
+    #[test]
+    fn auto_scroll_delta_stops_at_the_ends() {
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 300.0));
+        let delta = |y| auto_scroll_delta(&Axis::Vertical, bounds, &viewport, Point::new(50.0, y));
+
+        assert_eq!(delta(5.0), None);
+        assert_eq!(delta(95.0), Some(Vector::new(0.0, 14.0)));
+    }
+
+    #[test]
+    fn auto_scroll_delta_in_a_small_viewport() {
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(100.0, 40.0));
+        let bounds = Rectangle::new(Point::new(0.0, -50.0), Size::new(100.0, 300.0));
+        let delta = |y| auto_scroll_delta(&Axis::Vertical, bounds, &viewport, Point::new(50.0, y));
+
+        // The zones shrink to half of the viewport so that they do not overlap
+        assert_eq!(delta(20.0), None);
+        assert_eq!(delta(10.0), Some(Vector::new(0.0, -8.0)));
+        assert_eq!(delta(30.0), Some(Vector::new(0.0, 8.0)));
+    }
+
+    #[test]
+    fn is_near_edge_along_the_axis() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(200.0, 100.0));
+
+        assert!(is_near_edge(
+            &Axis::Vertical,
+            bounds,
+            Point::new(100.0, 10.0)
+        ));
+        assert!(!is_near_edge(
+            &Axis::Vertical,
+            bounds,
+            Point::new(10.0, 50.0)
+        ));
+        assert!(is_near_edge(
+            &Axis::Horizontal,
+            bounds,
+            Point::new(10.0, 50.0)
+        ));
+        assert!(!is_near_edge(
+            &Axis::Horizontal,
+            bounds,
+            Point::new(100.0, 10.0)
+        ));
+
+        // The zones shrink with the bounds like the ones of auto_scroll_delta
+        let small = Rectangle::new(Point::ORIGIN, Size::new(100.0, 40.0));
+        assert!(!is_near_edge(
+            &Axis::Vertical,
+            small,
+            Point::new(50.0, 20.0)
+        ));
+        assert!(is_near_edge(&Axis::Vertical, small, Point::new(50.0, 19.0)));
+    }
+
+    #[test]
+    fn keyboard_slot_moves_along_the_axis() {
+        use keyboard::key::Named;
+
+        let slot =
+            |axis, key, drop_location, wrap| keyboard_slot(&axis, key, drop_location, 3, wrap);
+
+        assert_eq!(slot(Axis::Vertical, Named::ArrowDown, 1, false), Some(2));
+        assert_eq!(slot(Axis::Vertical, Named::ArrowUp, 1, false), Some(0));
+        assert_eq!(slot(Axis::Vertical, Named::ArrowLeft, 1, false), None);
+        assert_eq!(slot(Axis::Horizontal, Named::ArrowRight, 1, false), Some(2));
+        assert_eq!(slot(Axis::Horizontal, Named::ArrowDown, 1, false), None);
+
+        assert_eq!(slot(Axis::Vertical, Named::ArrowUp, 0, false), Some(0));
+        assert_eq!(slot(Axis::Vertical, Named::ArrowDown, 3, false), Some(3));
+        assert_eq!(slot(Axis::Vertical, Named::ArrowUp, 0, true), Some(3));
+        assert_eq!(slot(Axis::Vertical, Named::ArrowDown, 3, true), Some(0));
+    }
+
+    #[test]
+    fn drop_target_in_the_middle_of_a_child() {
+        for axis in [Axis::Horizontal, Axis::Vertical] {
+            let node = widget(&axis, 3);
+            let layout = Layout::new(&node);
+            let target =
+                |main| drop_target(&axis, &layout, &[0, 1, 2], 0, at(&axis, main), |_| true);
+
+            assert_eq!(target(40.0), Some(1));
+            // Near the edges of a child element, the drop goes next to it
+            assert_eq!(target(31.0), None);
+            // The dragged child element is never a target
+            assert_eq!(target(10.0), None);
+        }
+    }
+
+    #[test]
+    fn slot_bounds_and_reveal_delta() {
+        let node = widget(&Axis::Vertical, 3);
+        let layout = Layout::new(&node);
+        let children = || layout.children().map(|item_layout| item_layout.bounds());
+        let dragged = Rectangle::new(Point::ORIGIN, Size::new(100.0, LENGTH));
+
+        assert_eq!(slot_bounds(&Axis::Vertical, children(), dragged, 2).y, 60.0);
+        assert_eq!(slot_bounds(&Axis::Vertical, children(), dragged, 3).y, 80.0);
+
+        let viewport = Rectangle::new(Point::new(0.0, 30.0), Size::new(100.0, 40.0));
+        let reveal = |y| reveal_delta(&Axis::Vertical, Rectangle { y, ..dragged }, &viewport);
+
+        assert_eq!(reveal(40.0), None);
+        assert_eq!(reveal(0.0), Some(Vector::new(0.0, -30.0)));
+        assert_eq!(reveal(60.0), Some(Vector::new(0.0, 10.0)));
+    }
+
+    #[test]
+    fn drag_state_info_and_translation() {
+        let node = widget(&Axis::Vertical, 2);
+        let layout = Layout::new(&node);
+        let grabbed = DragState::Grabbed {
+            key: 1,
+            origin: Point::new(10.0, 35.0),
+            offset: Vector::new(10.0, 5.0),
+            hold_until: None,
+        };
+        let dragged = DragState::Dragged {
+            key: 1,
+            origin: Point::new(10.0, 35.0),
+            offset: Vector::new(10.0, 5.0),
+            position: Point::new(10.0, 5.0),
+            drop_location: 0,
+        };
+
+        assert_eq!(DragState::<usize>::Idle.info(), None);
+        assert_eq!(
+            grabbed.info(),
+            Some(DragInfo {
+                key: 1,
+                drop_location: None
+            })
+        );
+        assert_eq!(dragged.info().and_then(|info| info.drop_location), Some(0));
+        assert_eq!(
+            grabbed.follow_translation([0, 1].iter().zip(layout.children())),
+            None
+        );
+        assert_eq!(
+            dragged.follow_translation([0, 1].iter().zip(layout.children())),
+            Some((1, Vector::new(0.0, -30.0)))
+        );
+    }
+
+    /// The keys of the children of the test widget.
+    const KEYS: [usize; 3] = [0, 1, 2];
+
+    /// The messages of the [`Stack`] gesture.
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Drag(usize, usize),
+        Drop(usize, usize),
+        Cancel(usize),
+    }
+
+    /// The basic gesture over the vertical children of the test widget, with an optional
+    /// hold delay measured at `now`.
+    struct Stack {
+        threshold: f32,
+        hold: Option<Duration>,
+        now: Instant,
+    }
+
+    impl Stack {
+        fn new() -> Self {
+            Self {
+                threshold: 0.0,
+                hold: None,
+                now: Instant::now(),
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct StackState {
+        drag: DragState<usize>,
+        picked_up: bool,
+        focused: Option<usize>,
+    }
+
+    impl Gesture<usize, Message> for Stack {
+        type State = StackState;
+
+        fn drag<'s>(&self, state: &'s mut StackState) -> &'s mut DragState<usize> {
+            &mut state.drag
+        }
+
+        fn grab_at(
+            &mut self,
+            _state: &mut StackState,
+            _shell: &mut Shell<'_, Message>,
+            layout: Layout<'_>,
+            _event: &Event,
+            position: Point,
+        ) -> Option<(usize, Rectangle)> {
+            child_at(&KEYS, layout, position)
+        }
+
+        fn drop_location(&self, layout: Layout<'_>, _key: usize, position: Point) -> usize {
+            drop_location(&Axis::Vertical, &layout, position)
+        }
+
+        fn drag_message(&self, key: usize, drop_location: usize) -> Option<Message> {
+            Some(Message::Drag(key, drop_location))
+        }
+
+        fn drop_message(&self, key: usize, drop_location: usize) -> Option<Message> {
+            Some(Message::Drop(key, drop_location))
+        }
+
+        fn cancel_message(&self, key: usize) -> Option<Message> {
+            Some(Message::Cancel(key))
+        }
+
+        fn drag_threshold(&self) -> f32 {
+            self.threshold
+        }
+
+        fn hold(&self) -> Option<Hold> {
+            self.hold.map(|delay| Hold {
+                delay,
+                now: self.now,
+            })
+        }
+    }
+
+    impl PickUp<usize, Message> for Stack {
+        fn picked_up<'s>(&self, state: &'s mut StackState) -> &'s mut bool {
+            &mut state.picked_up
+        }
+
+        fn focused(&self, state: &StackState) -> Option<usize> {
+            state.focused
+        }
+
+        fn len(&self) -> usize {
+            KEYS.len()
+        }
+
+        fn pick_up(
+            &self,
+            state: &mut StackState,
+            _shell: &mut Shell<'_, Message>,
+            layout: Layout<'_>,
+            key: usize,
+        ) -> bool {
+            pick_up(&mut state.drag, &layout, &KEYS, key, |position| {
+                drop_location(&Axis::Vertical, &layout, position)
+            })
+        }
+    }
+
+    /// Sends the `event` with the cursor at `main` along the test widget to the gesture,
+    /// returning the produced messages and whether the event has been captured.
+    fn send(
+        gesture: &mut Stack,
+        state: &mut StackState,
+        event: Event,
+        main: f32,
+    ) -> (Vec<Message>, bool) {
+        let node = widget(&Axis::Vertical, 3);
+        let layout = Layout::new(&node);
+        let cursor = mouse::Cursor::Available(at(&Axis::Vertical, main));
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        update_picked_up(
+            &Axis::Vertical,
+            gesture,
+            state,
+            &event,
+            layout,
+            cursor,
+            &mut shell,
+            &node.bounds(),
+        );
+        update(gesture, state, &event, layout, cursor, &mut shell);
+        let is_captured = shell.is_event_captured();
+
+        (messages, is_captured)
+    }
+
+    fn press() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    fn release() -> Event {
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    }
+
+    fn moved(main: f32) -> Event {
+        Event::Mouse(mouse::Event::CursorMoved {
+            position: at(&Axis::Vertical, main),
+        })
+    }
+
+    fn key(named: keyboard::key::Named, code: keyboard::key::Code) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            modified_key: keyboard::Key::Named(named),
+            physical_key: keyboard::key::Physical::Code(code),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: None,
+            repeat: false,
+        })
+    }
+
+    #[test]
+    fn update_press_move_and_release_drops() {
+        let mut gesture = Stack::new();
+        let mut state = StackState::default();
+
+        assert_eq!(send(&mut gesture, &mut state, press(), 5.0), (vec![], true));
+        assert!(matches!(state.drag, DragState::Grabbed { key: 0, .. }));
+        assert_eq!(
+            send(&mut gesture, &mut state, moved(45.0), 45.0),
+            (vec![Message::Drag(0, 2)], true)
+        );
+        // Moving within the same drop location produces no messages
+        assert_eq!(send(&mut gesture, &mut state, moved(50.0), 50.0).0, []);
+        assert_eq!(
+            send(&mut gesture, &mut state, release(), 50.0),
+            (vec![Message::Drop(0, 2)], true)
+        );
+        assert_eq!(state.drag, DragState::Idle);
+    }
+
+    #[test]
+    fn update_ignores_presses_outside_of_children() {
+        let mut gesture = Stack::new();
+        let mut state = StackState::default();
+
+        assert_eq!(
+            send(&mut gesture, &mut state, press(), 25.0),
+            (vec![], false)
+        );
+        assert_eq!(
+            send(&mut gesture, &mut state, moved(45.0), 45.0),
+            (vec![], false)
+        );
+        assert_eq!(state.drag, DragState::Idle);
+    }
+
+    #[test]
+    fn update_release_without_drag_cancels_the_grab() {
+        let mut gesture = Stack::new();
+        let mut state = StackState::default();
+
+        send(&mut gesture, &mut state, press(), 35.0);
+        assert_eq!(
+            send(&mut gesture, &mut state, release(), 35.0),
+            (vec![Message::Cancel(1)], true)
+        );
+        assert_eq!(state.drag, DragState::Idle);
+    }
+
+    #[test]
+    fn update_waits_for_the_drag_threshold() {
+        let mut gesture = Stack {
+            threshold: 10.0,
+            ..Stack::new()
+        };
+        let mut state = StackState::default();
+
+        send(&mut gesture, &mut state, press(), 5.0);
+        assert_eq!(
+            send(&mut gesture, &mut state, moved(12.0), 12.0),
+            (vec![], false)
+        );
+        assert!(matches!(state.drag, DragState::Grabbed { .. }));
+        assert_eq!(
+            send(&mut gesture, &mut state, moved(17.0), 17.0).0,
+            [Message::Drag(0, 1)]
+        );
+    }
+
+    #[test]
+    fn update_releases_the_grab_consumed_by_another_widget() {
+        let mut gesture = Stack::new();
+        let mut state = StackState::default();
+        let node = widget(&Axis::Vertical, 3);
+        let cursor = mouse::Cursor::Available(at(&Axis::Vertical, 45.0));
+        let mut messages = Vec::new();
+
+        send(&mut gesture, &mut state, press(), 5.0);
+        let mut shell = Shell::new(&mut messages);
+        shell.capture_event();
+        update(
+            &mut gesture,
+            &mut state,
+            &moved(45.0),
+            Layout::new(&node),
+            cursor,
+            &mut shell,
+        );
+
+        assert_eq!(messages, []);
+        assert_eq!(state.drag, DragState::Idle);
+    }
+
+    #[test]
+    fn update_cancels_the_drag() {
+        use keyboard::key::{Code, Named};
+
+        let cancels = [
+            (key(Named::Escape, Code::Escape), true),
+            (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)),
+                true,
+            ),
+            // Other widgets should learn about the focus loss as well
+            (Event::Window(window::Event::Unfocused), false),
+        ];
+        for (event, is_captured) in cancels {
+            let mut gesture = Stack::new();
+            let mut state = StackState::default();
+
+            send(&mut gesture, &mut state, press(), 5.0);
+            send(&mut gesture, &mut state, moved(45.0), 45.0);
+            assert_eq!(
+                send(&mut gesture, &mut state, event, 45.0),
+                (vec![Message::Cancel(0)], is_captured)
+            );
+            assert_eq!(state.drag, DragState::Idle);
+            // The release ending the cancelled drag is left to the other widgets
+            assert_eq!(
+                send(&mut gesture, &mut state, release(), 45.0),
+                (vec![], false)
+            );
+        }
+    }
+
+    #[test]
+    fn update_ignores_other_keys_and_idle_cancels() {
+        use keyboard::key::{Code, Named};
+
+        let mut gesture = Stack::new();
+        let mut state = StackState::default();
+
+        let escape = key(Named::Escape, Code::Escape);
+        assert_eq!(send(&mut gesture, &mut state, escape, 5.0), (vec![], false));
+        send(&mut gesture, &mut state, press(), 5.0);
+        send(&mut gesture, &mut state, moved(45.0), 45.0);
+        let enter = key(Named::Enter, Code::Enter);
+        assert_eq!(send(&mut gesture, &mut state, enter, 45.0), (vec![], false));
+        assert!(matches!(state.drag, DragState::Dragged { .. }));
+    }
+
+    #[test]
+    fn update_holds_mouse_presses() {
+        let mut gesture = Stack {
+            hold: Some(Duration::from_millis(500)),
+            ..Stack::new()
+        };
+        let mut state = StackState::default();
+
+        // Moving before the delay elapsed releases the grab
+        send(&mut gesture, &mut state, press(), 5.0);
+        assert_eq!(
+            send(&mut gesture, &mut state, moved(45.0), 45.0),
+            (vec![Message::Cancel(0)], false)
+        );
+        assert_eq!(state.drag, DragState::Idle);
+
+        send(&mut gesture, &mut state, press(), 5.0);
+        gesture.now += Duration::from_millis(500);
+        assert_eq!(
+            send(&mut gesture, &mut state, moved(45.0), 45.0).0,
+            [Message::Drag(0, 2)]
+        );
+    }
+
+    #[test]
+    fn update_does_not_hold_touches() {
+        let mut gesture = Stack {
+            hold: Some(Duration::from_millis(500)),
+            ..Stack::new()
+        };
+        let mut state = StackState::default();
+        let id = touch::Finger(0);
+        let position = at(&Axis::Vertical, 5.0);
+
+        let pressed = Event::Touch(touch::Event::FingerPressed { id, position });
+        send(&mut gesture, &mut state, pressed, 5.0);
+        let position = at(&Axis::Vertical, 45.0);
+        let moved = Event::Touch(touch::Event::FingerMoved { id, position });
+        assert_eq!(
+            send(&mut gesture, &mut state, moved, 45.0).0,
+            [Message::Drag(0, 2)]
+        );
+    }
+
+    #[test]
+    fn update_picked_up_moves_with_the_keyboard() {
+        use keyboard::key::{Code, Named};
+
+        let mut gesture = Stack::new();
+        let mut state = StackState {
+            focused: Some(0),
+            ..StackState::default()
+        };
+
+        assert_eq!(
+            send(
+                &mut gesture,
+                &mut state,
+                key(Named::Space, Code::Space),
+                0.0
+            ),
+            (vec![], true)
+        );
+        assert!(state.picked_up);
+        assert_eq!(state.drag.drop_location(), Some(1));
+        assert_eq!(
+            send(
+                &mut gesture,
+                &mut state,
+                key(Named::ArrowDown, Code::ArrowDown),
+                0.0
+            ),
+            (vec![Message::Drag(0, 2)], true)
+        );
+        assert_eq!(
+            send(
+                &mut gesture,
+                &mut state,
+                key(Named::Enter, Code::Enter),
+                0.0
+            ),
+            (vec![Message::Drop(0, 2)], true)
+        );
+        assert!(!state.picked_up);
+        assert_eq!(state.drag, DragState::Idle);
+    }
+
+    #[test]
+    fn update_picked_up_drops_with_a_press() {
+        use keyboard::key::{Code, Named};
+
+        let mut gesture = Stack::new();
+        let mut state = StackState {
+            focused: Some(0),
+            ..StackState::default()
+        };
+
+        send(
+            &mut gesture,
+            &mut state,
+            key(Named::Space, Code::Space),
+            0.0,
+        );
+        assert_eq!(
+            send(&mut gesture, &mut state, press(), 75.0),
+            (vec![Message::Drop(0, 3)], true)
+        );
+        assert_eq!(state.drag, DragState::Idle);
+    }
+
+    #[test]
+    fn gesture_drag_to_reports_new_drop_locations_only() {
+        let gesture = Stack::new();
+        let mut state = StackState::default();
+        let node = widget(&Axis::Vertical, 3);
+        let layout = Layout::new(&node);
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        gesture.drag_to(&mut state, &mut shell, layout, at(&Axis::Vertical, 45.0));
+        assert_eq!(state.drag, DragState::Idle);
+        state.drag = grabbed(
+            &press(),
+            (
+                1,
+                Rectangle::new(Point::new(0.0, 30.0), Size::new(100.0, 20.0)),
+            ),
+            at(&Axis::Vertical, 35.0),
+            None,
+        );
+        for main in [45.0, 50.0, 75.0] {
+            gesture.drag_to(&mut state, &mut shell, layout, at(&Axis::Vertical, main));
+        }
+
+        assert_eq!(messages, [Message::Drag(1, 2), Message::Drag(1, 3)]);
+    }
+}
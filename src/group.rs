@@ -0,0 +1,208 @@
+//! Share drags between several reorderable columns.
+use crate::payload::Payload;
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// A handle shared by several [`Column`](crate::Column)s, letting child elements be
+/// dragged out of one column and dropped into another.
+///
+/// Create the group once, keep it in the application state, and pass it to each
+/// participating column with [`Column::drag_group`](crate::Column::drag_group). Dropping a
+/// child element into another column of the group produces the message set with
+/// [`Column::on_transfer`](crate::Column::on_transfer) on the receiving column; the source
+/// column produces no drop message. Dropping it outside of all the columns of the group
+/// cancels the drag. A [`TrashZone`](crate::TrashZone) of the group deletes the child
+/// elements dropped on it instead.
+///
+/// The keys of the child elements must be unique across all the columns of the group.
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::{row, text};
+/// use iced_reorderable::{Column, DragGroup};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(u64, usize),
+///     Transferred(usize, u64, usize),
+/// }
+///
+/// struct Lists {
+///     group: DragGroup<u64>,
+///     lists: [Vec<(u64, String)>; 2],
+/// }
+///
+/// fn view(lists: &Lists) -> iced::Element<'_, Message> {
+///     let column = |index: usize| {
+///         Column::with_children(
+///             lists.lists[index]
+///                 .iter()
+///                 .map(|(id, item)| (*id, text(item.as_str()).into())),
+///         )
+///         .drag_group(&lists.group)
+///         .on_drop(Message::Dropped)
+///         .on_transfer(move |key, slot| Message::Transferred(index, key, slot))
+///     };
+///
+///     row![column(0), column(1)].spacing(20).into()
+/// }
+/// ```
+pub struct DragGroup<Key> {
+    shared: Rc<RefCell<Shared<Key>>>,
+}
+
+/// The drag state shared by the columns of a [`DragGroup`].
+struct Shared<Key> {
+    /// The number of member columns that have joined the group.
+    members: usize,
+    /// The number of drags started in the group.
+    drags: u64,
+    /// The drag in progress, if any.
+    drag: Option<Drag<Key>>,
+    /// The drag released over another member, which may not have delivered it yet.
+    released: Option<u64>,
+    /// The payload attached to the drag in progress or released, if any.
+    payload: Option<Payload>,
+    /// The member hovered by the drag in progress, if it is not the source.
+    target: Option<usize>,
+}
+
+/// A drag in progress in a [`DragGroup`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Drag<Key> {
+    /// The number identifying the drag.
+    pub(crate) id: u64,
+    /// The key of the dragged child element.
+    pub(crate) key: Key,
+    /// The member the child element is dragged from.
+    pub(crate) source: usize,
+}
+
+impl<Key> DragGroup<Key>
+where
+    Key: Copy,
+{
+    /// Creates a new [`DragGroup`] without members.
+    pub fn new() -> Self {
+        Self {
+            shared: Rc::new(RefCell::new(Shared {
+                members: 0,
+                drags: 0,
+                drag: None,
+                released: None,
+                payload: None,
+                target: None,
+            })),
+        }
+    }
+
+    /// Returns the number identifying a new member of the group.
+    pub(crate) fn join(&self) -> usize {
+        let mut shared = self.shared.borrow_mut();
+        shared.members += 1;
+        shared.members
+    }
+
+    /// Starts a drag of the child element with the given key out of the `source` member,
+    /// carrying the given payload.
+    pub(crate) fn start(&self, key: Key, source: usize, payload: Option<Payload>) {
+        let mut shared = self.shared.borrow_mut();
+        shared.drags += 1;
+        shared.drag = Some(Drag {
+            id: shared.drags,
+            key,
+            source,
+        });
+        shared.released = None;
+        shared.payload = payload;
+        shared.target = None;
+    }
+
+    /// Returns the drag in progress, if any.
+    pub(crate) fn drag(&self) -> Option<Drag<Key>> {
+        self.shared.borrow().drag
+    }
+
+    /// Sets whether the drag in progress hovers the given member.
+    pub(crate) fn hover(&self, member: usize, is_hovered: bool) {
+        let mut shared = self.shared.borrow_mut();
+        if is_hovered {
+            shared.target = Some(member);
+        } else if shared.target == Some(member) {
+            shared.target = None;
+        }
+    }
+
+    /// Returns the member hovered by the drag in progress, if it is not the source.
+    pub(crate) fn target(&self) -> Option<usize> {
+        self.shared.borrow().target
+    }
+
+    /// Ends the drag in progress by releasing it over the hovered member.
+    pub(crate) fn release(&self) {
+        let mut shared = self.shared.borrow_mut();
+        shared.released = shared.drag.take().map(|drag| drag.id);
+        shared.target = None;
+    }
+
+    /// Ends the drag in progress without dropping it anywhere.
+    pub(crate) fn cancel(&self) {
+        let mut shared = self.shared.borrow_mut();
+        shared.drag = None;
+        shared.released = None;
+        shared.payload = None;
+        shared.target = None;
+    }
+
+    /// Returns whether the drag with the given number is in progress or has been released
+    /// over another member, i.e. whether it can still be dropped into a member.
+    pub(crate) fn is_live(&self, id: u64) -> bool {
+        let shared = self.shared.borrow();
+        shared.drag.is_some_and(|drag| drag.id == id) || shared.released == Some(id)
+    }
+
+    /// Returns the payload of the drag with the given number, if it is live and carries one.
+    pub(crate) fn payload_of(&self, id: u64) -> Option<Payload> {
+        self.is_live(id)
+            .then(|| self.shared.borrow().payload.clone())
+            .flatten()
+    }
+
+    /// Returns the [`Payload`] of the drag in progress in the group, if any.
+    ///
+    /// Lets custom drop targets decide whether they accept the dragged child element
+    /// while it is dragged, before it is dropped.
+    pub fn payload(&self) -> Option<Payload> {
+        let shared = self.shared.borrow();
+        shared.drag.and(shared.payload.clone())
+    }
+}
+
+impl<Key> Clone for DragGroup<Key> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Rc::clone(&self.shared),
+        }
+    }
+}
+
+impl<Key> Default for DragGroup<Key>
+where
+    Key: Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Key> fmt::Debug for DragGroup<Key> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shared = self.shared.borrow();
+        f.debug_struct("DragGroup")
+            .field("members", &shared.members)
+            .field("is_dragging", &shared.drag.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,262 @@
+//! Typed indices distinguishing the two index conventions of the reorderable widgets.
+//!
+//! Drop locations are insertion slots between the children, ranging over `0..=len`, while
+//! the children themselves are indexed over `0..len`. Mixing up the two is the main source
+//! of off-by-one errors when applying a drop to the application state; the conversions of
+//! these types encode the adjustment rules instead.
+
+/// An insertion slot among the children of a reorderable widget, in `0..=len`.
+///
+/// Slot `i` is in front of the child element at [`ItemIndex`] `i`, and slot `len` is after
+/// the last child element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SlotIndex(pub usize);
+
+/// The index of a child element of a reorderable widget, in `0..len`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ItemIndex(pub usize);
+
+impl SlotIndex {
+    /// Returns the index the child element at `from` ends up at when moved to this slot.
+    ///
+    /// Slots after the child element shift down by one, since the child element is removed
+    /// from in front of them.
+    pub fn target(self, from: ItemIndex) -> ItemIndex {
+        if self.0 > from.0 {
+            ItemIndex(self.0 - 1)
+        } else {
+            ItemIndex(self.0)
+        }
+    }
+
+    /// Returns whether moving the child element at `from` to this slot keeps the order
+    /// unchanged, i.e. the slot is right in front of or right after the child element.
+    pub fn is_noop(self, from: ItemIndex) -> bool {
+        self == from.slot_before() || self == from.slot_after()
+    }
+}
+
+impl ItemIndex {
+    /// Returns the slot right in front of the child element.
+    pub fn slot_before(self) -> SlotIndex {
+        SlotIndex(self.0)
+    }
+
+    /// Returns the slot right after the child element.
+    pub fn slot_after(self) -> SlotIndex {
+        SlotIndex(self.0 + 1)
+    }
+}
+
+impl From<usize> for SlotIndex {
+    fn from(index: usize) -> Self {
+        Self(index)
+    }
+}
+
+impl From<SlotIndex> for usize {
+    fn from(index: SlotIndex) -> Self {
+        index.0
+    }
+}
+
+impl From<usize> for ItemIndex {
+    fn from(index: usize) -> Self {
+        Self(index)
+    }
+}
+
+impl From<ItemIndex> for usize {
+    fn from(index: ItemIndex) -> Self {
+        index.0
+    }
+}
+
+/// Moves the item at `from` to the slot `to`, applying a drop to the application state.
+///
+/// # Example
+/// ```
+/// use iced_reorderable::index::{move_item, ItemIndex, SlotIndex};
+///
+/// let mut items = vec!["a", "b", "c", "d"];
+///
+/// // Drop "a" in front of "d"
+/// move_item(&mut items, ItemIndex(0), SlotIndex(3));
+/// assert_eq!(items, ["b", "c", "a", "d"]);
+///
+/// // Drop "d" after the last item
+/// move_item(&mut items, ItemIndex(3), SlotIndex(4));
+/// assert_eq!(items, ["b", "c", "a", "d"]);
+/// ```
+pub fn move_item<T>(items: &mut Vec<T>, from: ItemIndex, to: SlotIndex) {
+    if from.0 >= items.len() || to.is_noop(from) {
+        return;
+    }
+    let item = items.remove(from.0);
+    items.insert(to.target(from).0.min(items.len()), item);
+}
+
+/// Moves the items at the indices `from` to the slot `to`, keeping their relative order,
+/// applying a drop of several items to the application state.
+///
+/// # Example
+/// ```
+/// use iced_reorderable::index::{move_items, ItemIndex, SlotIndex};
+///
+/// let mut items = vec!["a", "b", "c", "d", "e"];
+///
+/// // Drop "a" and "c" in front of "e"
+/// move_items(&mut items, &[ItemIndex(0), ItemIndex(2)], SlotIndex(4));
+/// assert_eq!(items, ["b", "d", "a", "c", "e"]);
+/// ```
+pub fn move_items<T>(items: &mut Vec<T>, from: &[ItemIndex], to: SlotIndex) {
+    let mut from: Vec<usize> = from
+        .iter()
+        .map(|index| index.0)
+        .filter(|index| *index < items.len())
+        .collect();
+    from.sort_unstable();
+    from.dedup();
+
+    // The slot shifts down by one for each moved item in front of it
+    let target = to.0.min(items.len()) - from.iter().filter(|index| **index < to.0).count();
+    let mut moved = Vec::with_capacity(from.len());
+    for index in from.iter().rev() {
+        moved.push(items.remove(*index));
+    }
+    moved.reverse();
+
+    let tail = items.split_off(target);
+    items.extend(moved);
+    items.extend(tail);
+}
+
+/// The position of a drop relative to the other child elements, naming a neighbor instead
+/// of an index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Anchor<Key> {
+    /// The child element is dropped right in front of the child element with the key.
+    Before(Key),
+    /// The child element is dropped right after the child element with the key, which is
+    /// the last one.
+    After(Key),
+    /// The child element is dropped where there are no other child elements.
+    IntoEmpty,
+}
+
+impl<Key> Anchor<Key>
+where
+    Key: Copy + PartialEq,
+{
+    /// Returns the [`Anchor`] of the child element with the key `dragged` dropped at the
+    /// slot `to` among the child elements with the given keys.
+    ///
+    /// The dragged child element itself is never used as the neighbor, so a drop right in
+    /// front of itself is anchored before the child element following it.
+    ///
+    /// # Example
+    /// ```
+    /// use iced_reorderable::index::{Anchor, SlotIndex};
+    ///
+    /// let keys = ["a", "b", "c"];
+    ///
+    /// assert_eq!(Anchor::of(&keys, "a", SlotIndex(2)), Anchor::Before("c"));
+    /// assert_eq!(Anchor::of(&keys, "a", SlotIndex(3)), Anchor::After("c"));
+    /// assert_eq!(Anchor::of(&keys, "c", SlotIndex(2)), Anchor::After("b"));
+    /// assert_eq!(Anchor::of(&["a"], "a", SlotIndex(0)), Anchor::IntoEmpty);
+    /// ```
+    pub fn of(keys: &[Key], dragged: Key, to: SlotIndex) -> Self {
+        let to = to.0.min(keys.len());
+
+        if let Some(next) = keys[to..].iter().find(|key| **key != dragged) {
+            Anchor::Before(*next)
+        } else if let Some(previous) = keys[..to].iter().rev().find(|key| **key != dragged) {
+            Anchor::After(*previous)
+        } else {
+            Anchor::IntoEmpty
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_shifts_slots_after_the_item() {
+        assert_eq!(SlotIndex(0).target(ItemIndex(2)), ItemIndex(0));
+        assert_eq!(SlotIndex(2).target(ItemIndex(2)), ItemIndex(2));
+        assert_eq!(SlotIndex(3).target(ItemIndex(2)), ItemIndex(2));
+        assert_eq!(SlotIndex(5).target(ItemIndex(2)), ItemIndex(4));
+    }
+
+    #[test]
+    fn is_noop_around_the_item() {
+        assert!(SlotIndex(2).is_noop(ItemIndex(2)));
+        assert!(SlotIndex(3).is_noop(ItemIndex(2)));
+        assert!(!SlotIndex(1).is_noop(ItemIndex(2)));
+        assert!(!SlotIndex(4).is_noop(ItemIndex(2)));
+    }
+
+    #[test]
+    fn move_item_to_every_slot() {
+        let moved = |to| {
+            let mut items = vec!["a", "b", "c"];
+            move_item(&mut items, ItemIndex(1), SlotIndex(to));
+            items
+        };
+
+        assert_eq!(moved(0), ["b", "a", "c"]);
+        assert_eq!(moved(1), ["a", "b", "c"]);
+        assert_eq!(moved(2), ["a", "b", "c"]);
+        assert_eq!(moved(3), ["a", "c", "b"]);
+        assert_eq!(moved(10), ["a", "c", "b"]);
+    }
+
+    #[test]
+    fn move_item_out_of_range() {
+        let mut items = vec!["a", "b"];
+        move_item(&mut items, ItemIndex(2), SlotIndex(0));
+
+        assert_eq!(items, ["a", "b"]);
+    }
+
+    #[test]
+    fn move_items_keeps_their_order() {
+        let mut items = vec!["a", "b", "c", "d", "e"];
+        move_items(&mut items, &[ItemIndex(3), ItemIndex(1)], SlotIndex(0));
+
+        assert_eq!(items, ["b", "d", "a", "c", "e"]);
+
+        let mut items = vec!["a", "b", "c", "d", "e"];
+        move_items(&mut items, &[ItemIndex(0), ItemIndex(1)], SlotIndex(5));
+
+        assert_eq!(items, ["c", "d", "e", "a", "b"]);
+    }
+
+    #[test]
+    fn move_items_ignores_duplicates_and_out_of_range() {
+        let mut items = vec!["a", "b", "c"];
+        move_items(
+            &mut items,
+            &[ItemIndex(0), ItemIndex(0), ItemIndex(7)],
+            SlotIndex(2),
+        );
+
+        assert_eq!(items, ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn anchor_of_skips_the_dragged_key() {
+        let keys = ["a", "b", "c"];
+
+        assert_eq!(Anchor::of(&keys, "b", SlotIndex(0)), Anchor::Before("a"));
+        assert_eq!(Anchor::of(&keys, "b", SlotIndex(1)), Anchor::Before("c"));
+        assert_eq!(Anchor::of(&keys, "a", SlotIndex(3)), Anchor::After("c"));
+        assert_eq!(Anchor::of(&keys, "c", SlotIndex(10)), Anchor::After("b"));
+        assert_eq!(
+            Anchor::of(&[] as &[&str], "a", SlotIndex(0)),
+            Anchor::IntoEmpty
+        );
+    }
+}
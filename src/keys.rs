@@ -0,0 +1,123 @@
+//! Intern string identifiers into keys usable with the reorderable widgets.
+use std::collections::HashMap;
+
+/// A key interner mapping string identifiers to stable [`KeyId`] keys.
+///
+/// The reorderable widgets require `Copy` keys. Applications identifying their items
+/// with strings can intern the identifiers when building the view, and resolve the keys
+/// back to the identifiers in their message handlers.
+///
+/// # Example
+/// ```
+/// use iced_reorderable::Keys;
+///
+/// let mut keys = Keys::new();
+///
+/// let key = keys.intern("8f14e45f-ceea-4b6a");
+/// assert_eq!(keys.intern("8f14e45f-ceea-4b6a"), key);
+/// assert_eq!(keys.resolve(key), Some("8f14e45f-ceea-4b6a"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Keys {
+    ids: HashMap<String, KeyId>,
+    strings: Vec<String>,
+}
+
+/// A `Copy` key interned by [`Keys`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyId(usize);
+
+impl Keys {
+    /// Creates an empty [`Keys`] interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the [`KeyId`] of the given string identifier, interning it if needed.
+    ///
+    /// The same identifier always produces the same [`KeyId`] for a given [`Keys`].
+    pub fn intern(&mut self, id: &str) -> KeyId {
+        if let Some(key) = self.ids.get(id) {
+            return *key;
+        }
+        let key = KeyId(self.strings.len());
+        self.strings.push(id.to_owned());
+        let _ = self.ids.insert(id.to_owned(), key);
+        key
+    }
+
+    /// Returns the [`KeyId`] of the given string identifier, if it has been interned.
+    pub fn get(&self, id: &str) -> Option<KeyId> {
+        self.ids.get(id).copied()
+    }
+
+    /// Returns the string identifier of the given [`KeyId`].
+    pub fn resolve(&self, key: KeyId) -> Option<&str> {
+        self.strings.get(key.0).map(String::as_str)
+    }
+
+    /// Returns the number of interned identifiers.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns whether no identifiers have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Shrinks the capacity of the interner as much as possible.
+    ///
+    /// Interned identifiers are never forgotten, since their [`KeyId`] must stay stable,
+    /// but the buffers may grow beyond their needs while interning.
+    pub fn shrink_to_fit(&mut self) {
+        self.ids.shrink_to_fit();
+        self.strings.shrink_to_fit();
+    }
+
+    /// Returns the [`MemoryStats`](crate::stats::MemoryStats) of the interner.
+    #[cfg(feature = "memory-stats")]
+    pub fn memory_stats(&self) -> crate::stats::MemoryStats {
+        let strings: usize = self.strings.iter().map(String::capacity).sum();
+        let ids: usize = self.ids.keys().map(String::capacity).sum();
+
+        crate::stats::MemoryStats {
+            len: self.strings.len(),
+            capacity: self.strings.capacity(),
+            heap_bytes: crate::stats::heap_bytes(&self.strings)
+                + self.ids.capacity() * std::mem::size_of::<(String, KeyId)>()
+                + strings
+                + ids,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intern_is_stable() {
+        let mut keys = Keys::new();
+        let first = keys.intern("first");
+        let second = keys.intern("second");
+
+        assert_ne!(first, second);
+        assert_eq!(keys.intern("first"), first);
+        assert_eq!(keys.len(), 2);
+    }
+
+    #[test]
+    fn get_and_resolve() {
+        let mut keys = Keys::new();
+
+        assert!(keys.is_empty());
+        assert_eq!(keys.get("id"), None);
+
+        let key = keys.intern("id");
+
+        assert_eq!(keys.get("id"), Some(key));
+        assert_eq!(keys.resolve(key), Some("id"));
+        assert_eq!(keys.resolve(KeyId(1)), None);
+    }
+}
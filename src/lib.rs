@@ -1,24 +1,101 @@
+pub mod animation;
+pub mod board;
+pub mod drag_handle;
+pub mod dropdown;
+pub mod error;
+mod flex;
+pub mod group;
+pub mod index;
+pub mod keys;
+pub mod list;
+pub mod meta;
+pub mod no_drag;
+pub mod operation;
+pub mod payload;
+pub mod row;
+pub mod scroll;
+pub mod simulate;
+#[cfg(feature = "memory-stats")]
+pub mod stats;
+pub mod style;
+pub mod trash;
+pub mod virtualized;
+pub mod wrap;
+
+pub use animation::{DragAnimator, Easing};
+pub use board::{Board, Lane};
+pub use drag_handle::{drag_handle, DragHandle};
+pub use dropdown::Dropdown;
+pub use error::ReorderError;
+pub use group::DragGroup;
+pub use index::{Anchor, ItemIndex, SlotIndex};
+pub use keys::{KeyId, Keys};
+pub use meta::WithMeta;
+pub use no_drag::{no_drag, NoDrag};
+pub use payload::Payload;
+pub use row::Row;
+pub use scroll::ReorderableScrollable;
+pub use trash::TrashZone;
+pub use virtualized::VirtualColumn;
+pub use wrap::Wrap;
+
+use animation::Timed;
+use flex::DragState;
+
 use iced::advanced::layout;
+use iced::advanced::layout::flex::Axis;
 use iced::advanced::overlay;
 use iced::advanced::renderer;
-use iced::advanced::widget::{Operation, Tree, Widget};
+use iced::advanced::widget::operation::{Focusable, Outcome, Scrollable, TextInput};
+use iced::advanced::widget::{Id, Operation, Tree, Widget};
 use iced::advanced::Clipboard;
 use iced::advanced::Layout;
 use iced::advanced::Shell;
 use iced::alignment::{self, Alignment};
-use iced::border::Radius;
+use iced::keyboard;
 use iced::mouse;
+use iced::time::{Duration, Instant};
 use iced::touch;
-use iced::Border;
+use iced::window;
 use iced::Color;
 use iced::Event;
 use iced::Point;
 use iced::Theme;
-use iced::{Element, Length, Padding, Pixels, Rectangle, Size, Vector};
+use iced::{
+    Border, Element, Length, Padding, Pixels, Rectangle, Shadow, Size, Task, Transformation, Vector,
+};
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+
+/// A message produced with the key of the dropped child element and its [`Anchor`].
+type AnchorFn<'a, Key, Message> = Box<dyn Fn(Key, Anchor<Key>) -> Message + 'a>;
+type SectionDropFn<'a, Key, Message> = Box<dyn Fn(Key, Key, usize) -> Message + 'a>;
+/// A function drawing the drop position marker with the given bounds.
+type MarkerFn<'a, Theme, Renderer> = Box<dyn Fn(&mut Renderer, Rectangle, &Theme) + 'a>;
+/// A message produced with the keys of the dropped child elements and the drop location.
+type SelectionDropFn<'a, Key, Message> = Box<dyn Fn(Vec<Key>, usize) -> Message + 'a>;
 
 /// A container that distributes its contents vertically and allows dragging
 /// and dropping its keyed children.
 ///
+/// The [`Column`] captures the pointer events it handles as part of a drag gesture: the
+/// press grabbing a child element, the moves while the child element is dragged, and the
+/// release or cancellation ending the gesture. Parent widgets coordinating gestures of
+/// their own can check [`Shell::is_event_captured`] to avoid handling the same events.
+///
+/// Presses outside of the child elements, i.e. in the padding, the spacing, or below the
+/// last child element, are never captured. This lets a [`Column`] coexist with parents
+/// dragging on their own, e.g. inside of a [`PaneGrid`]: pressing an item reorders the
+/// items, while pressing the empty space or the title bar of the pane is left to the
+/// [`PaneGrid`]. See the `panes` example for the combination.
+///
+/// [`PaneGrid`]: iced::widget::PaneGrid
+///
+/// The grabbable child elements of a [`Column`] producing drop messages take part in
+/// the focus operations, e.g. `focus_next` bound to Tab: the focused child element is
+/// outlined, Space picks it up, the arrow keys move it and Enter drops it. Pressing the
+/// mouse or touching the screen clears the focus.
+///
 /// # Example
 /// ```no_run
 /// use super::Column;
@@ -73,17 +150,82 @@ where
     max_width: f32,
     align: Alignment,
     clip: bool,
-    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    id: Option<Id>,
+    children: RefCell<Vec<Element<'a, Message, Theme, Renderer>>>,
     keys: Vec<Key>,
+    placeholders: Vec<bool>,
+    statics: Vec<(usize, Element<'a, Message, Theme, Renderer>)>,
+    sections: Vec<usize>,
+    collapsed: Vec<(usize, usize)>,
+    status_view: Option<StatusView<'a, Key, Message, Theme, Renderer>>,
+    lazy_view: Option<LazyView<'a, Key, Message, Theme, Renderer>>,
     class: Theme::Class<'a>,
     on_grab: Option<Box<dyn Fn(Key) -> Message + 'a>>,
     on_drag: Option<Box<dyn Fn(Key, usize) -> Message + 'a>>,
+    on_drag_move: Option<Box<dyn Fn(Key, Point) -> Message + 'a>>,
     on_drop: Option<Box<dyn Fn(Key, usize) -> Message + 'a>>,
+    on_copy_drop: Option<Box<dyn Fn(Key, usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    on_drop_event: Option<Box<dyn Fn(DropEvent<Key>) -> Message + 'a>>,
+    on_drop_anchor: Option<AnchorFn<'a, Key, Message>>,
+    on_drop_position: Option<Box<dyn Fn(Key, Point) -> Message + 'a>>,
+    on_drop_outside: Option<Box<dyn Fn(Key, Point) -> Message + 'a>>,
+    on_swap: Option<Box<dyn Fn(Key, Key) -> Message + 'a>>,
+    on_drop_onto: Option<Box<dyn Fn(Key, Key) -> Message + 'a>>,
+    accepts_drop: Option<Box<dyn Fn(Key, usize) -> bool + 'a>>,
+    drop_range: Option<Box<dyn Fn(Key) -> Range<usize> + 'a>>,
+    on_section_drop: Option<SectionDropFn<'a, Key, Message>>,
+    on_error: Option<Box<dyn Fn(ReorderError) -> Message + 'a>>,
     on_cancel: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_transfer: Option<Box<dyn Fn(Key, usize) -> Message + 'a>>,
+    on_transfer_payload: Option<Box<dyn Fn(Payload, usize) -> Message + 'a>>,
+    payload: Option<Box<dyn Fn(Key) -> Payload + 'a>>,
+    on_select: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_click: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_double_click: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    selected: Option<Key>,
+    selection: Vec<Key>,
+    pinned: Vec<Key>,
+    on_selection_change: Option<Box<dyn Fn(Vec<Key>) -> Message + 'a>>,
+    on_drop_selection: Option<SelectionDropFn<'a, Key, Message>>,
+    rubber_band: bool,
+    on_explain: Option<Box<dyn Fn(DropExplanation<Key>) -> Message + 'a>>,
+    on_marker: Option<Box<dyn Fn(Option<Rectangle>) -> Message + 'a>>,
+    on_gap_hover: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_hover: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_hover_exit: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_auto_scroll: Option<Box<dyn Fn(Vector) -> Message + 'a>>,
+    on_gap_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    explain_drops: bool,
     drop_position_marker: bool,
+    draw_marker: Option<MarkerFn<'a, Theme, Renderer>>,
+    presentation: Presentation,
+    drop_mode: DropMode,
+    drag_exit: DragExit,
+    grab_policy: GrabPolicy,
+    on_drag_exit: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_drag_enter: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    origin_placeholder: OriginPlaceholder,
+    animator: Option<Box<dyn DragAnimator + 'a>>,
     drag_follow: bool,
+    drag_opacity: f32,
+    drag_scale: f32,
+    drag_shadow: Option<Shadow>,
     drag_lateral: bool,
     drag_center: bool,
+    redraw_on_hover: bool,
+    frame_sync: bool,
+    hide_sibling_overlays: bool,
+    drag_threshold: f32,
+    mouse_hold_delay: Option<Duration>,
+    clock: flex::TimeSource<'a>,
+    click_to_drag: bool,
+    keyboard_wrap: bool,
+    reorder_buttons: bool,
+    drag_group: Option<DragGroup<Key>>,
+    item_max_height: f32,
+    incremental_layout: Option<usize>,
+    revision: u64,
 }
 
 impl<'a, Key, Message, Theme, Renderer> Column<'a, Key, Message, Theme, Renderer>
@@ -93,6 +235,9 @@ where
     Theme: Catalog,
     Renderer: iced::advanced::Renderer,
 {
+    /// The main axis along which the children are dragged.
+    const AXIS: Axis = Axis::Vertical;
+
     /// Creates an empty [`Column`].
     pub fn new() -> Self {
         Self::from_vecs(Vec::new(), Vec::new())
@@ -112,6 +257,61 @@ where
         Self::with_capacity(iterator.size_hint().0).extend(iterator)
     }
 
+    /// Creates a [`Column`] with the given keys, building a child element for each key
+    /// with the given `view` function.
+    ///
+    /// The `view` function receives the [`ItemStatus`] of the child element, reflecting
+    /// the internal drag state of the [`Column`]. Whenever the status of a child element
+    /// changes, the element is rebuilt with the new status. This removes the need to
+    /// mirror the drag state in the application state for styling purposes.
+    pub fn with_children_status(
+        keys: impl IntoIterator<Item = Key>,
+        view: impl Fn(Key, ItemStatus) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        let keys: Vec<Key> = keys.into_iter().collect();
+        let children = keys
+            .iter()
+            .map(|key| view(*key, ItemStatus::Idle))
+            .collect();
+
+        let mut column = Self::from_vecs(keys, children);
+        let children = column.children.get_mut();
+        for child in children.iter() {
+            let child_size = child.as_widget().size_hint();
+            column.width = column.width.enclose(child_size.width);
+            column.height = column.height.enclose(child_size.height);
+        }
+        column.status_view = Some(StatusView {
+            view: Box::new(view),
+            statuses: RefCell::new(vec![ItemStatus::Idle; column.keys.len()]),
+        });
+        column
+    }
+
+    /// Creates a [`Column`] with the given keys, whose child elements are only built with
+    /// the given `view` function when the [`Column`] is first needed, i.e. when its widget
+    /// tree is created or reconciled, rather than upfront.
+    ///
+    /// The [`Column`] lays out all of its children, so once it is needed, every child
+    /// element is built: a shown [`Column`] builds all of them on every view, like
+    /// [`Column::with_children`]. Only a [`Column`] that is never shown, e.g. in a `lazy`
+    /// widget or in a hidden tab, costs no more than its keys. Use a [`VirtualColumn`] to
+    /// build only the child elements in view of lists too long to build in full.
+    ///
+    /// The [`Column`] adapts to the sizing strategy of its children once they are built.
+    pub fn lazy(
+        keys: impl IntoIterator<Item = Key>,
+        view: impl Fn(Key) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        let keys: Vec<Key> = keys.into_iter().collect();
+        let mut column = Self::from_vecs(keys, Vec::new());
+        column.lazy_view = Some(LazyView {
+            view: Box::new(view),
+            size: Cell::new(Size::new(Length::Shrink, Length::Shrink)),
+        });
+        column
+    }
+
     /// Creates a [`Column`] from an already allocated [`Vec`].
     ///
     /// Keep in mind that the [`Column`] will not inspect the [`Vec`], which means
@@ -128,17 +328,82 @@ where
             max_width: f32::INFINITY,
             align: Alignment::Start,
             clip: false,
+            id: None,
+            placeholders: vec![false; keys.len()],
+            statics: Vec::new(),
+            sections: Vec::new(),
+            collapsed: Vec::new(),
             keys,
-            children,
+            children: RefCell::new(children),
+            status_view: None,
+            lazy_view: None,
             class: Theme::default(),
             on_grab: None,
             on_drag: None,
+            on_drag_move: None,
             on_drop: None,
+            on_copy_drop: None,
+            on_reorder: None,
+            on_drop_event: None,
+            on_drop_anchor: None,
+            on_drop_position: None,
+            on_drop_outside: None,
+            on_swap: None,
+            on_drop_onto: None,
+            accepts_drop: None,
+            drop_range: None,
+            on_section_drop: None,
+            on_error: None,
             on_cancel: None,
+            on_transfer: None,
+            on_transfer_payload: None,
+            payload: None,
+            on_select: None,
+            on_click: None,
+            on_double_click: None,
+            selected: None,
+            selection: Vec::new(),
+            pinned: Vec::new(),
+            on_selection_change: None,
+            on_drop_selection: None,
+            rubber_band: false,
+            on_explain: None,
+            on_marker: None,
+            on_gap_hover: None,
+            on_hover: None,
+            on_hover_exit: None,
+            on_auto_scroll: None,
+            on_gap_click: None,
+            explain_drops: false,
             drop_position_marker: true,
+            draw_marker: None,
+            presentation: Presentation::Marker,
+            drop_mode: DropMode::Insert,
+            drag_exit: DragExit::Keep,
+            grab_policy: GrabPolicy::Uncaptured,
+            on_drag_exit: None,
+            on_drag_enter: None,
+            origin_placeholder: OriginPlaceholder::Hide,
+            animator: None,
             drag_follow: false,
+            drag_opacity: 1.0,
+            drag_scale: 1.0,
+            drag_shadow: None,
             drag_lateral: false,
             drag_center: false,
+            redraw_on_hover: true,
+            frame_sync: false,
+            hide_sibling_overlays: false,
+            drag_threshold: 0.0,
+            mouse_hold_delay: None,
+            clock: flex::TimeSource::default(),
+            click_to_drag: false,
+            keyboard_wrap: false,
+            reorder_buttons: false,
+            drag_group: None,
+            item_max_height: f32::INFINITY,
+            incremental_layout: None,
+            revision: 0,
         }
     }
 
@@ -165,6 +430,10 @@ where
     }
 
     /// Sets the height of the [`Column`].
+    ///
+    /// With a fixed height, child elements with a [`Length::Fill`] or [`Length::FillPortion`]
+    /// height share the space left over by the other child elements according to their
+    /// fill factors, like in an iced column.
     pub fn height(mut self, height: impl Into<Length>) -> Self {
         self.height = height.into();
         self
@@ -182,6 +451,41 @@ where
         self
     }
 
+    /// Sets the maximum height of each child element of the [`Column`].
+    ///
+    /// Taller child elements are laid out with the maximum height as a limit, and any
+    /// content overflowing it is clipped. This keeps a single oversized child element
+    /// from making dragging around it awkward.
+    ///
+    /// Child elements with a [`Length::Fill`] or [`Length::FillPortion`] height are
+    /// clamped as well. The space they cannot take, along with the space freed by the
+    /// other clamped child elements, is shared among the remaining filling child elements
+    /// by their fill factors, like [`Column::height`] describes.
+    pub fn item_max_height(mut self, item_max_height: impl Into<Pixels>) -> Self {
+        self.item_max_height = item_max_height.into().0;
+        self
+    }
+
+    /// Sets the number of child elements measured per frame when the [`Column`] is first
+    /// laid out, spreading the initial layout of a huge list over several frames.
+    ///
+    /// Until all child elements are measured, they are stacked with their own heights,
+    /// like in a [`Scrollable`](iced::widget::Scrollable), and the child elements not
+    /// measured yet are drawn as placeholder rows with the average height so far. Later
+    /// layouts are done at once, as usual.
+    pub fn incremental_layout(mut self, children_per_frame: usize) -> Self {
+        self.incremental_layout = Some(children_per_frame.max(1));
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Column`], identifying it to operations like
+    /// [`operation::geometry`], [`operation::drag_state`], [`operation::focus`],
+    /// [`operation::grab`], [`operation::scroll_by`] and [`operation::scroll_to`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets whether the contents of the [`Column`] should be clipped on
     /// overflow.
     ///
@@ -204,11 +508,113 @@ where
         self.width = self.width.enclose(child_size.width);
         self.height = self.height.enclose(child_size.height);
 
+        self.build_lazy_children();
         self.keys.push(key);
-        self.children.push(child);
+        self.placeholders.push(false);
+        self.children.get_mut().push(child);
+        self
+    }
+
+    /// Adds a placeholder element to the [`Column`], e.g. a loading stub for an item whose
+    /// data has not been loaded yet.
+    ///
+    /// Placeholders cannot be grabbed, and the dragged child element is never dropped on
+    /// or between placeholders. Drop locations next to a regular child element remain
+    /// valid, so dropping after the last loaded item is possible.
+    pub fn push_placeholder(
+        mut self,
+        key: Key,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self = self.push(key, child);
+        if let Some(is_placeholder) = self.placeholders.last_mut() {
+            *is_placeholder = true;
+        }
+        self
+    }
+
+    /// Adds a static element to the [`Column`], e.g. a separator or a heading.
+    ///
+    /// Static elements are laid out in the flow like the other child elements, but they
+    /// have no key: they cannot be grabbed, and the drop locations only count the keyed
+    /// child elements, so a static element between two of them is skipped. Static
+    /// elements are not displaced by [`Presentation::Displace`].
+    pub fn push_static(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        let child = child.into();
+        let child_size = child.as_widget().size_hint();
+
+        self.width = self.width.enclose(child_size.width);
+        self.height = self.height.enclose(child_size.height);
+
+        self.build_lazy_children();
+        self.statics.push((self.keys.len(), child));
         self
     }
 
+    /// Adds the header of a new section to the [`Column`], which groups the children
+    /// pushed after it until the next section header.
+    ///
+    /// Headers cannot be grabbed, and identify their section with their key in
+    /// [`Column::on_section_drop`]. The dragged child element can be dropped right after a
+    /// header, even if its section is empty, but never in front of the first header.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use iced::widget::text;
+    /// use iced_reorderable::Column;
+    ///
+    /// #[derive(Debug, Clone, Copy, PartialEq)]
+    /// enum Key {
+    ///     Section(usize),
+    ///     Setting(u64),
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Moved(Key, Key, usize),
+    /// }
+    ///
+    /// fn view<'a>(sections: &'a [(&'a str, Vec<(u64, String)>)]) -> iced::Element<'a, Message> {
+    ///     sections
+    ///         .iter()
+    ///         .enumerate()
+    ///         .fold(Column::new(), |column, (index, (title, settings))| {
+    ///             settings.iter().fold(
+    ///                 column.push_section(Key::Section(index), text(*title)),
+    ///                 |column, (id, setting)| column.push(Key::Setting(*id), text(setting)),
+    ///             )
+    ///         })
+    ///         .on_section_drop(Message::Moved)
+    ///         .into()
+    /// }
+    /// ```
+    pub fn push_section(
+        mut self,
+        key: Key,
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.sections.push(self.keys.len());
+        self.push_placeholder(key, header)
+    }
+
+    /// Adds the header of a new collapsed section to the [`Column`], whose `hidden`
+    /// children are left out of the [`Column`].
+    ///
+    /// The header behaves like the ones added with [`Column::push_section`], except that
+    /// the dragged child element is dropped into the collapsed section when it is over the
+    /// header, which is then highlighted. Such drops append the child element to the
+    /// section: [`Column::on_section_drop`] reports the index `hidden`. Collapsing and
+    /// expanding the section is up to the application, e.g. with a button in the header.
+    pub fn push_collapsed_section(
+        mut self,
+        key: Key,
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        hidden: usize,
+    ) -> Self {
+        self.collapsed.push((self.keys.len(), hidden));
+        self.push_section(key, header)
+    }
+
     /// Adds an element to the [`Column`], if `Some`.
     pub fn push_maybe(
         self,
@@ -223,18 +629,49 @@ where
     }
 
     /// Extends the [`Column`] with the given children.
+    ///
+    /// Space for the children is reserved upfront based on the size hint of the iterator,
+    /// so that a [`Column`] can be built efficiently from chunks, e.g. pages of items
+    /// loaded incrementally.
     pub fn extend(
-        self,
+        mut self,
         children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
     ) -> Self {
-        children
-            .into_iter()
-            .fold(self, |items, (key, child)| items.push(key, child))
+        let iterator = children.into_iter();
+        let additional = iterator.size_hint().0;
+
+        self.keys.reserve(additional);
+        self.placeholders.reserve(additional);
+        self.children.get_mut().reserve(additional);
+
+        iterator.fold(self, |items, (key, child)| items.push(key, child))
+    }
+
+    /// Returns the [`MemoryStats`](stats::MemoryStats) of the buffers of the [`Column`].
+    ///
+    /// The state the widget keeps between views is reallocated to fit whenever the keys
+    /// of the children change, so that it shrinks along with the list, e.g. after clearing
+    /// a queue. Only the buffers of the [`Column`] itself can exceed its children, e.g.
+    /// when built with a [`Column::with_capacity`] larger than needed.
+    #[cfg(feature = "memory-stats")]
+    pub fn memory_stats(&self) -> stats::MemoryStats {
+        let children = self.children.borrow();
+
+        stats::MemoryStats {
+            len: children.len() + self.statics.len(),
+            capacity: children.capacity().min(self.keys.capacity()),
+            heap_bytes: stats::heap_bytes(&children)
+                + stats::heap_bytes(&self.keys)
+                + stats::heap_bytes(&self.placeholders)
+                + stats::heap_bytes(&self.sections)
+                + stats::heap_bytes(&self.collapsed)
+                + stats::heap_bytes(&self.statics),
+        }
     }
 
     /// Sets the style of the [`Column`].
     #[must_use]
-    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
     where
         Theme::Class<'a>: From<StyleFn<'a, Theme>>,
     {
@@ -275,6 +712,20 @@ where
         self
     }
 
+    /// Sets the message that will be produced on every move of the cursor or touch while a
+    /// child element is being dragged, not only when its drop location changes.
+    ///
+    /// The message will be produced with the key of the dragged child element and the
+    /// position of the cursor or touch, e.g. to drive a custom preview or a tooltip
+    /// tracking the drag.
+    pub fn on_drag_move<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, Point) -> Message + 'a,
+    {
+        self.on_drag_move = Some(Box::new(message));
+        self
+    }
+
     /// Sets the message that will be produced when the dragged child element is dropped in
     /// a valid drop location on the [`Column`].
     ///
@@ -288,8 +739,149 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the dragged child element is dropped at
+    /// a new position among the [`Column`] children, with the indices to move it between.
+    ///
+    /// The message will be produced with the index of the dragged child element and the
+    /// index it ends up at, already adjusted for its removal from in front of the drop
+    /// location, so that removing the item at the first index and inserting it at the
+    /// second one is all that is left to do. Drops keeping the order unchanged produce no
+    /// message. It is not produced with [`DropMode::Swap`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # let mut items = vec!["a", "b", "c"];
+    /// # let (from, to) = (0, 2);
+    /// // Handling `Message::Reordered(from, to)` produced with `.on_reorder(Message::Reordered)`
+    /// let item = items.remove(from);
+    /// items.insert(to, item);
+    /// ```
+    pub fn on_reorder<F>(mut self, message: F) -> Self
+    where
+        F: Fn(usize, usize) -> Message + 'a,
+    {
+        self.on_reorder = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced instead of the other drop messages when the
+    /// dragged child element is dropped while Ctrl is held, e.g. to duplicate it like file
+    /// managers do.
+    ///
+    /// The message will be produced with the key of the dragged child element and the index
+    /// of the drop position among the [`Column`] children, like [`Column::on_drop`]. It is
+    /// not produced with [`DropMode::Swap`].
+    pub fn on_copy_drop<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, usize) -> Message + 'a,
+    {
+        self.on_copy_drop = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced with a [`DropEvent`] when the dragged child
+    /// element is dropped in a valid drop location on the [`Column`].
+    ///
+    /// This is an extended alternative to [`Column::on_drop`], providing additional details
+    /// of the drop.
+    pub fn on_drop_event<F>(mut self, message: F) -> Self
+    where
+        F: Fn(DropEvent<Key>) -> Message + 'a,
+    {
+        self.on_drop_event = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced with an [`Anchor`] when the dragged child
+    /// element is dropped on the [`Column`].
+    ///
+    /// The message will be produced with the key of the dragged child element and the key
+    /// of its new neighbor, which maps directly to ordering models without indices, like
+    /// linked lists or database order columns.
+    pub fn on_drop_anchor<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, Anchor<Key>) -> Message + 'a,
+    {
+        self.on_drop_anchor = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced with the final position of the dragged child
+    /// element when it is dropped on the [`Column`], instead of snapping it into a slot.
+    ///
+    /// The message will be produced with the key of the dragged child element and the
+    /// position of its top-left corner relative to the top-left corner of the [`Column`],
+    /// e.g. to arrange the child elements freely like on a canvas. The child element moves
+    /// freely in both directions, no drop position marker is shown and no other children
+    /// make room for it; enable [`Column::drag_follow`] to preview the position. The other
+    /// drop messages are not produced while this one is set.
+    pub fn on_drop_position<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, Point) -> Message + 'a,
+    {
+        self.on_drop_position = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when the dragged child element is released
+    /// outside of the bounds of the [`Column`], e.g. to remove it when dragged out of the
+    /// list.
+    ///
+    /// The message will be produced with the key of the dragged child element and the
+    /// release position, instead of the other drop messages. The child element moves
+    /// freely in both directions while this message is set, so that it can leave the
+    /// [`Column`]; no drop position marker is shown while it is outside. A member of a
+    /// [`DragGroup`] produces this message only if no other member receives the drop.
+    pub fn on_drop_outside<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, Point) -> Message + 'a,
+    {
+        self.on_drop_outside = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced with the target section when the dragged
+    /// child element is dropped on the [`Column`].
+    ///
+    /// The message will be produced with the key of the dragged child element, the key of
+    /// the header of the section it is dropped into, see [`Column::push_section`], and the
+    /// index of the drop position among the children of that section, following the same
+    /// convention as [`Column::on_drop`]. Drops in front of the first section header
+    /// produce no such message.
+    pub fn on_section_drop<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, Key, usize) -> Message + 'a,
+    {
+        self.on_section_drop = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when a misconfiguration of the [`Column`]
+    /// is detected, in debug builds.
+    ///
+    /// Each [`ReorderError`] is reported once for the lifetime of the widget state.
+    pub fn on_error<F>(mut self, message: F) -> Self
+    where
+        F: Fn(ReorderError) -> Message + 'a,
+    {
+        self.on_error = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the revision of the order of the [`Column`] children, which will be reported
+    /// back in the [`DropEvent`] of a drop.
+    ///
+    /// Applications keeping a monotonic revision of their ordering, e.g. one synchronized
+    /// with a server, can compare it against the reported revision to detect that a drop
+    /// was made on a stale ordering, and refetch instead of applying it.
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.revision = revision;
+        self
+    }
+
     /// Sets the message that will be produced when the user cancels active dragging by
-    /// right-clicking or when the dragging touch is lost.
+    /// right-clicking, by pressing Escape, or when the dragging touch is lost. Active
+    /// dragging is canceled as well when the window loses focus.
     ///
     /// The message will be produced with the key of the child element that was being dragged.
     pub fn on_cancel<F>(mut self, message: F) -> Self
@@ -300,114 +892,2456 @@ where
         self
     }
 
-    /// Sets whether a marker line will be shown for the position among the [`Column`] children,
-    /// where the dragged child element would be dropped if mouse button press or touch was
-    /// released at current position.
-    pub fn drop_position_marker(mut self, drop_position_marker: bool) -> Self {
-        self.drop_position_marker = drop_position_marker;
+    /// Sets the message that will be produced when a child element is tapped on a touch
+    /// screen, enabling the select-then-drag touch flow.
+    ///
+    /// With the flow enabled, only the child element set with [`Column::selected`] can be
+    /// dragged by touch. Touching any other child element does not grab it, and lifting the
+    /// finger without moving it produces the message with the key of the tapped child
+    /// element, which the application can use to select it. This avoids long presses
+    /// entirely. Mouse presses are not affected.
+    pub fn on_select<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_select = Some(Box::new(message));
         self
     }
 
-    /// Sets whether a child element should follow the cursor or touch while being dragged.
-    pub fn drag_follow(mut self, drag_follow: bool) -> Self {
-        self.drag_follow = drag_follow;
+    /// Sets the message that will be produced when a child element is clicked, i.e.
+    /// pressed and released over it without moving past the [`Column::drag_threshold`].
+    ///
+    /// The message will be produced with the key of the clicked child element, after the
+    /// [`Column::on_cancel`] message ending the grab. It is not produced for the clicks
+    /// picking up a child element with [`Column::click_to_drag`].
+    pub fn on_click<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_click = Some(Box::new(message));
         self
     }
 
-    /// Sets whether a child element should follow the cursor laterally on the cross axis of the
-    /// [`Column`] while being dragged.
+    /// Sets the message that will be produced when a child element is clicked twice in a
+    /// row, e.g. to open it.
     ///
-    /// If set to `false`, child elements will only follow the cursor vertically along the main
-    /// axis of the [`Column`] while being dragged.
+    /// The message will be produced with the key of the child element after the
+    /// [`Column::on_click`] message of the second click.
+    pub fn on_double_click<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_double_click = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the key of the selected child element, the only one that can be dragged by
+    /// touch if [`Column::on_select`] is set.
+    pub fn selected(mut self, key: Option<Key>) -> Self {
+        self.selected = key;
+        self
+    }
+
+    /// Sets the keys of the child elements selected for dragging them together.
     ///
-    /// This has no effect if [`Column::drag_follow`] is set to `false`.
-    pub fn drag_lateral(mut self, drag_lateral: bool) -> Self {
-        self.drag_lateral = drag_lateral;
+    /// The selection is owned by the application, which updates it with the keys produced
+    /// by [`Column::on_selection_change`] and styles the selected child elements.
+    pub fn selection(mut self, keys: impl IntoIterator<Item = Key>) -> Self {
+        self.selection = keys.into_iter().collect();
         self
     }
 
-    /// Sets whether a child element should be centered on the cursor while being dragged.
+    /// Sets the keys of the pinned child elements, which keep their position, e.g. an
+    /// inbox that always comes first.
     ///
-    /// This has no effect if [`Column::drag_follow`] is set to `false`.
-    pub fn drag_center(mut self, drag_center: bool) -> Self {
-        self.drag_center = drag_center;
+    /// Pinned child elements cannot be grabbed, and the other child elements are never
+    /// dropped at a drop location that would move a pinned one to another index.
+    pub fn pinned(mut self, keys: impl IntoIterator<Item = Key>) -> Self {
+        self.pinned = keys.into_iter().collect();
         self
     }
-}
 
-impl<'a, Key, Message, Theme, Renderer> Default for Column<'a, Key, Message, Theme, Renderer>
-where
-    Key: Copy + PartialEq,
-    Message: Clone,
-    Theme: Catalog,
-    Renderer: iced::advanced::Renderer,
-{
-    fn default() -> Self {
-        Self::new()
+    /// Sets the message that will be produced when the selection of the [`Column`] changes
+    /// by clicking its children.
+    ///
+    /// The message will be produced with the keys of the new selection. Clicking with the
+    /// command modifier (Ctrl on most platforms) toggles the child element, clicking with
+    /// the Shift modifier selects the range from the last clicked child element, and
+    /// pressing an unselected child element selects only that one. Modified clicks do not
+    /// start dragging.
+    pub fn on_selection_change<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Vec<Key>) -> Message + 'a,
+    {
+        self.on_selection_change = Some(Box::new(message));
+        self
     }
-}
 
-impl<'a, Key, Message, Theme, Renderer> FromIterator<(Key, Element<'a, Message, Theme, Renderer>)>
-    for Column<'a, Key, Message, Theme, Renderer>
-where
-    Key: Copy + PartialEq,
-    Message: Clone,
-    Theme: Catalog,
-    Renderer: iced::advanced::Renderer,
-{
-    fn from_iter<T: IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>>(
-        iter: T,
-    ) -> Self {
-        Self::with_children(iter)
+    /// Sets the message that will be produced when the dragged child element is dropped on
+    /// the [`Column`] along with the rest of the [`Column::selection`].
+    ///
+    /// The message will be produced with the keys of the dropped child elements in their
+    /// current order, which is only the dragged one if it is not selected, and the index of
+    /// the drop position among the [`Column`] children. Apply it with
+    /// [`index::move_items`]. The other drop messages are not produced while this one is
+    /// set.
+    pub fn on_drop_selection<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Vec<Key>, usize) -> Message + 'a,
+    {
+        self.on_drop_selection = Some(Box::new(message));
+        self
     }
-}
 
-impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
-    for Column<'a, Key, Message, Theme, Renderer>
-where
-    Key: Copy + PartialEq + 'static,
-    Message: Clone,
-    Theme: Catalog,
-    Renderer: iced::advanced::Renderer,
-{
-    fn state(&self) -> iced::advanced::widget::tree::State {
-        iced::advanced::widget::tree::State::new(State::<Key>::default())
+    /// Sets whether pressing the empty space of the [`Column`] and dragging draws a
+    /// selection rectangle, selecting the children it intersects.
+    ///
+    /// While the rectangle changes, [`Column::on_selection_change`] is produced with the
+    /// keys of the intersected child elements, added to the [`Column::selection`] if the
+    /// command modifier is held. Only mouse presses start a selection rectangle.
+    pub fn rubber_band_selection(mut self, rubber_band: bool) -> Self {
+        self.rubber_band = rubber_band;
+        self
     }
 
-    fn tag(&self) -> iced::advanced::widget::tree::Tag {
-        iced::advanced::widget::tree::Tag::of::<State<Key>>()
+    /// Sets whether the [`Column`] should explain each drop location decision it makes.
+    ///
+    /// When enabled, the message set with [`Column::on_explain`] will be produced with a
+    /// [`DropExplanation`] every time the drop location of the dragged child element is
+    /// resolved. Useful for diagnosing unexpected drop locations.
+    pub fn explain_drops(mut self, explain_drops: bool) -> Self {
+        self.explain_drops = explain_drops;
+        self
     }
 
-    fn children(&self) -> Vec<Tree> {
-        self.children.iter().map(Tree::new).collect()
+    /// Sets the message that will be produced with a [`DropExplanation`] when
+    /// [`Column::explain_drops`] is enabled.
+    pub fn on_explain<F>(mut self, message: F) -> Self
+    where
+        F: Fn(DropExplanation<Key>) -> Message + 'a,
+    {
+        self.on_explain = Some(Box::new(message));
+        self
     }
 
-    fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&self.children);
+    /// Sets the message that will be produced with the bounds of the drop position marker
+    /// whenever the marker moves to another drop location.
+    ///
+    /// The message will be produced with `None` when the dragged child element is dropped
+    /// or the drag is cancelled. The bounds are produced even if
+    /// [`Column::drop_position_marker`] is disabled, allowing the application to draw its
+    /// own decorations at the drop location, e.g. in an overlay.
+    pub fn on_marker<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Option<Rectangle>) -> Message + 'a,
+    {
+        self.on_marker = Some(Box::new(message));
+        self
     }
 
-    fn size(&self) -> Size<Length> {
-        Size {
-            width: self.width,
-            height: self.height,
-        }
+    /// Sets the message that will be produced when the cursor starts hovering a gap between
+    /// the [`Column`] children while no child element is being dragged.
+    ///
+    /// The message will be produced with the index of the hovered gap, using the same
+    /// indexing as drop locations. While a gap is hovered, a faint drop position marker is
+    /// shown in it, unless [`Column::drop_position_marker`] is disabled.
+    pub fn on_gap_hover<F>(mut self, message: F) -> Self
+    where
+        F: Fn(usize) -> Message + 'a,
+    {
+        self.on_gap_hover = Some(Box::new(message));
+        self
     }
 
-    fn update(
-        &mut self,
-        tree: &mut Tree,
-        event: &Event,
-        layout: Layout<'_>,
-        cursor: mouse::Cursor,
-        renderer: &Renderer,
-        clipboard: &mut dyn Clipboard,
-        shell: &mut Shell<'_, Message>,
-        viewport: &Rectangle,
-    ) {
-        let drag_state = tree.state.downcast_ref::<State<Key>>().drag;
-        if let Some((event, cursor)) = propagage_event_to_children(&drag_state, &event, cursor) {
-            for ((child, state), item_layout) in self
-                .children
+    /// Sets the message that will be produced when the cursor starts hovering a child
+    /// element while no child element is being dragged.
+    ///
+    /// The message will be produced with the key of the hovered child element, e.g. to
+    /// highlight its details elsewhere without wrapping every child element in a
+    /// [`mouse_area`](iced::widget::mouse_area).
+    pub fn on_hover<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_hover = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when the cursor stops hovering a child
+    /// element while no child element is being dragged.
+    ///
+    /// The message will be produced with the key of the child element hovered before,
+    /// ahead of the [`Column::on_hover`] message of the next hovered one.
+    pub fn on_hover_exit<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_hover_exit = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced on every frame while a child element is
+    /// dragged near the edges of the visible part of the [`Column`], e.g. when it lives in
+    /// a [`Scrollable`](iced::widget::Scrollable) and only part of it is in view.
+    ///
+    /// The message will be produced with the amount to scroll by, which grows as the
+    /// cursor or touch approaches the edge. Scroll the enclosing scrollable accordingly,
+    /// e.g. with [`scroll_by`], so that the children outside the view can be reached; the
+    /// drop location follows the content as it scrolls under the cursor. No message is
+    /// produced once the end of the [`Column`] is in view.
+    ///
+    /// A message is also produced when a picked up child element is moved out of the view
+    /// with the arrow keys, with the amount that brings its drop location back into view.
+    ///
+    /// ```no_run
+    /// # use iced::{Task, Vector};
+    /// # #[derive(Debug, Clone)]
+    /// # enum Message { AutoScroll(Vector) }
+    /// const LIST: &str = "list";
+    ///
+    /// fn update(message: Message) -> Task<Message> {
+    ///     match message {
+    ///         Message::AutoScroll(delta) => iced_reorderable::scroll_by(LIST, delta),
+    ///     }
+    /// }
+    /// ```
+    pub fn on_auto_scroll<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Vector) -> Message + 'a,
+    {
+        self.on_auto_scroll = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when a gap between the [`Column`] children,
+    /// including the padding, is clicked or tapped.
+    ///
+    /// The message will be produced with the index of the clicked gap, using the same
+    /// indexing as drop locations, e.g. to insert a new child element at that index.
+    pub fn on_gap_click<F>(mut self, message: F) -> Self
+    where
+        F: Fn(usize) -> Message + 'a,
+    {
+        self.on_gap_click = Some(Box::new(message));
+        self
+    }
+
+    /// Sets whether a marker line will be shown for the position among the [`Column`] children,
+    /// where the dragged child element would be dropped if mouse button press or touch was
+    /// released at current position.
+    pub fn drop_position_marker(mut self, drop_position_marker: bool) -> Self {
+        self.drop_position_marker = drop_position_marker;
+        self
+    }
+
+    /// Sets the function drawing the drop position marker, replacing the built-in line
+    /// with a circle at its start, e.g. to draw an insertion bar with an arrowhead.
+    ///
+    /// The function is called with the bounds of the built-in marker line, spanning the
+    /// child elements around the drop position. It draws in a layer above the child
+    /// elements, and is not called when [`Column::drop_position_marker`] is disabled.
+    pub fn marker<F>(mut self, draw: F) -> Self
+    where
+        F: Fn(&mut Renderer, Rectangle, &Theme) + 'a,
+    {
+        self.draw_marker = Some(Box::new(draw));
+        self
+    }
+
+    /// Sets how the drop location of the dragged child element is presented.
+    ///
+    /// Like the other settings, the [`Presentation`] can be changed between views, e.g.
+    /// to let users choose their preferred visualization. By default, a marker line is
+    /// shown, see [`Column::drop_position_marker`].
+    pub fn presentation(mut self, presentation: Presentation) -> Self {
+        self.presentation = presentation;
+        self
+    }
+
+    /// Sets what is left at the original position of the dragged child element while it
+    /// follows the cursor with [`Column::drag_follow`], see [`OriginPlaceholder`].
+    pub fn origin_placeholder(mut self, origin_placeholder: OriginPlaceholder) -> Self {
+        self.origin_placeholder = origin_placeholder;
+        self
+    }
+
+    /// Sets the [`DragAnimator`] making the child elements glide to their new positions
+    /// instead of snapping to them, e.g.
+    /// [`animation::Timed`](animation::Timed) with a duration and an [`Easing`].
+    ///
+    /// The siblings displaced by [`Presentation::Displace`] and [`Presentation::Gap`]
+    /// glide out of the way of the dragged child element, and after a drop the child
+    /// elements settle into the new order the application applied. The child element
+    /// following the cursor is not animated while dragged; once dropped, it settles from
+    /// the release position into its slot, in a short transition by default.
+    pub fn animator(mut self, animator: impl DragAnimator + 'a) -> Self {
+        self.animator = Some(Box::new(animator));
+        self
+    }
+
+    /// Sets what dropping the dragged child element does, see [`DropMode`].
+    ///
+    /// With [`DropMode::Swap`], the drop location is the index of the child element under
+    /// the dragged one, which is highlighted instead of showing a marker line. The
+    /// messages set with [`Column::on_drag`] and [`Column::on_drop`] are produced with
+    /// that index, and [`Column::on_swap`] with the keys of both child elements; the other
+    /// drop messages are not produced. Dropping the child element onto itself produces no
+    /// drop messages.
+    pub fn drop_mode(mut self, drop_mode: DropMode) -> Self {
+        self.drop_mode = drop_mode;
+        self
+    }
+
+    /// Sets what happens when the dragged child element leaves the bounds of the
+    /// [`Column`], see [`DragExit`].
+    ///
+    /// With [`DragExit::Cancel`], the child element moves freely in both directions, so
+    /// that it can leave the [`Column`] sideways, and leaving cancels the drag, producing
+    /// the [`Column::on_cancel`] message.
+    pub fn drag_exit(mut self, drag_exit: DragExit) -> Self {
+        self.drag_exit = drag_exit;
+        self
+    }
+
+    /// Sets which presses on the child elements grab them, see [`GrabPolicy`].
+    ///
+    /// With [`GrabPolicy::NonInteractive`], pressing a button, a text input or a checkbox
+    /// inside of a child element never grabs the child element, even if the pressed widget
+    /// does not capture the press.
+    pub fn grab_policy(mut self, grab_policy: GrabPolicy) -> Self {
+        self.grab_policy = grab_policy;
+        self
+    }
+
+    /// Sets the message that will be produced when the dragged child element leaves the
+    /// bounds of the [`Column`], e.g. to highlight an external drop target.
+    ///
+    /// The message will be produced with the key of the dragged child element. The child
+    /// element moves freely in both directions while this message is set.
+    pub fn on_drag_exit<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_drag_exit = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when the dragged child element enters the
+    /// bounds of the [`Column`] again after leaving them.
+    ///
+    /// The message will be produced with the key of the dragged child element.
+    pub fn on_drag_enter<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_drag_enter = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when the dragged child element is dropped
+    /// onto another one with [`DropMode::Swap`].
+    ///
+    /// The message will be produced with the key of the dragged child element and the key
+    /// of the child element to swap it with.
+    pub fn on_swap<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, Key) -> Message + 'a,
+    {
+        self.on_swap = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when the dragged child element is dropped
+    /// onto another child element rather than between two of them, e.g. to drop a file
+    /// onto a folder.
+    ///
+    /// The dragged child element targets another one while the cursor or touch is over its
+    /// middle half; the target is highlighted instead of showing a marker line. Dropping it
+    /// there produces this message with the key of the dragged child element and the key
+    /// of the target, instead of the other drop messages. Near the edges of the child
+    /// elements, the dragged child element is reordered as usual.
+    pub fn on_drop_onto<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, Key) -> Message + 'a,
+    {
+        self.on_drop_onto = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the predicate deciding whether the dragged child element with the given key
+    /// can be dropped at the given drop location, e.g. to keep the children from being
+    /// moved above a pinned first row.
+    ///
+    /// Rejected drop locations are skipped like the ones between placeholders: the
+    /// dragged child element is dropped at the nearest accepted drop location instead, and
+    /// no marker line is shown at a rejected one. If no drop location is accepted, the
+    /// drop is canceled, producing the [`Column::on_cancel`] message instead of the drop
+    /// messages. Child elements transferred from another member of a [`DragGroup`] are
+    /// not accepted at rejected drop locations either.
+    pub fn accepts_drop<F>(mut self, accepts_drop: F) -> Self
+    where
+        F: Fn(Key, usize) -> bool + 'a,
+    {
+        self.accepts_drop = Some(Box::new(accepts_drop));
+        self
+    }
+
+    /// Sets the function returning the range of drop locations the child element with the
+    /// given key can be dropped at, e.g. `0..3` for the top slots of a ranked list.
+    ///
+    /// While dragging, the drop location and its marker line are clamped to the range, and
+    /// the child element is dropped at the nearest drop location in the range. Drop
+    /// locations out of the range are rejected like the ones rejected by
+    /// [`Column::accepts_drop`].
+    pub fn drop_range<F>(mut self, drop_range: F) -> Self
+    where
+        F: Fn(Key) -> Range<usize> + 'a,
+    {
+        self.drop_range = Some(Box::new(drop_range));
+        self
+    }
+
+    /// Sets whether a child element should follow the cursor or touch while being dragged.
+    pub fn drag_follow(mut self, drag_follow: bool) -> Self {
+        self.drag_follow = drag_follow;
+        self
+    }
+
+    /// Sets the opacity of the child element following the cursor with
+    /// [`Column::drag_follow`], between `0.0` and `1.0`.
+    ///
+    /// The renderer cannot fade arbitrary widgets, so the opacity applies to the text color
+    /// the child element inherits; widgets drawing with colors of their own are not faded.
+    pub fn drag_opacity(mut self, drag_opacity: f32) -> Self {
+        self.drag_opacity = drag_opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the factor the child element following the cursor with [`Column::drag_follow`]
+    /// is scaled by around its center, e.g. `1.05` to lift it slightly off the [`Column`].
+    pub fn drag_scale(mut self, drag_scale: f32) -> Self {
+        self.drag_scale = drag_scale;
+        self
+    }
+
+    /// Sets the [`Shadow`] cast by the child element following the cursor with
+    /// [`Column::drag_follow`].
+    pub fn drag_shadow(mut self, drag_shadow: Shadow) -> Self {
+        self.drag_shadow = Some(drag_shadow);
+        self
+    }
+
+    /// Sets whether a child element should follow the cursor laterally on the cross axis of the
+    /// [`Column`] while being dragged.
+    ///
+    /// If set to `false`, child elements will only follow the cursor vertically along the main
+    /// axis of the [`Column`] while being dragged.
+    ///
+    /// This has no effect if [`Column::drag_follow`] is set to `false`.
+    pub fn drag_lateral(mut self, drag_lateral: bool) -> Self {
+        self.drag_lateral = drag_lateral;
+        self
+    }
+
+    /// Sets whether a child element should be centered on the cursor while being dragged.
+    ///
+    /// This has no effect if [`Column::drag_follow`] is set to `false`.
+    pub fn drag_center(mut self, drag_center: bool) -> Self {
+        self.drag_center = drag_center;
+        self
+    }
+
+    /// Sets whether the [`Column`] should request a redraw when the cursor moves over it
+    /// while no child element is being dragged.
+    ///
+    /// Disabling this avoids redundant frames when the child elements have no hover styling.
+    pub fn redraw_on_hover(mut self, redraw_on_hover: bool) -> Self {
+        self.redraw_on_hover = redraw_on_hover;
+        self
+    }
+
+    /// Sets whether the dragged child element should be moved at most once per frame.
+    ///
+    /// When enabled, the cursor and touch moves during a drag are coalesced, and only the
+    /// latest position is processed when the next frame is drawn. This avoids redundant
+    /// drop location updates and messages with high polling rate mice. By default, every
+    /// move is processed as it arrives.
+    pub fn frame_sync(mut self, frame_sync: bool) -> Self {
+        self.frame_sync = frame_sync;
+        self
+    }
+
+    /// Sets the distance the cursor or touch has to move from the grab position before a
+    /// grabbed child element starts being dragged.
+    ///
+    /// With a non-zero threshold, presses are delivered in two phases. The child elements
+    /// first receive the press and all following events as usual, so nested buttons see
+    /// the press immediately and can complete a click if the pointer is released before
+    /// crossing the threshold. Once the threshold is crossed, the drag materializes: the
+    /// crossing event is captured, and the child elements receive the remaining events of
+    /// the gesture without a cursor position. This revokes the press, and buttons are
+    /// released without being clicked instead of staying in their pressed state.
+    ///
+    /// If the gesture is consumed by another widget before the threshold is crossed, the
+    /// grab is released without producing any cancel or drop messages.
+    ///
+    /// With the default threshold of zero, the child elements stop receiving cursor positions
+    /// as soon as a child element is grabbed.
+    ///
+    /// This has no effect if [`Column::drag_center`] is set to `true`, since the child
+    /// element is then dragged immediately on press.
+    pub fn drag_threshold(mut self, drag_threshold: impl Into<Pixels>) -> Self {
+        self.drag_threshold = drag_threshold.into().0;
+        self
+    }
+
+    /// Sets how long the mouse button has to be held on a child element before it can be
+    /// dragged.
+    ///
+    /// If the cursor moves further than [`Column::drag_threshold`] before the delay has
+    /// elapsed, the grab is cancelled and the gesture is left to the child elements, e.g.
+    /// for selecting text. This only applies to mouse presses; touch presses are not
+    /// delayed. By default, there is no delay.
+    pub fn mouse_hold_delay(mut self, mouse_hold_delay: Option<Duration>) -> Self {
+        self.mouse_hold_delay = mouse_hold_delay;
+        self
+    }
+
+    /// Sets the clock the [`Column`] reads the current time from, instead of the system
+    /// clock.
+    ///
+    /// The time is used for the [`Column::mouse_hold_delay`], double clicks and the start
+    /// of animations. Use a [`simulate::Clock`] to test these flows with simulated
+    /// timestamps.
+    pub fn clock(mut self, clock: impl Fn() -> Instant + 'a) -> Self {
+        self.clock = flex::TimeSource::new(clock);
+        self
+    }
+
+    /// Sets whether a child element can be picked up with a click and dropped with a second
+    /// click, without holding the button while moving it.
+    ///
+    /// A press and release on a child element without moving further than a tap keeps the
+    /// child element dragged, following the cursor until the next press drops it. This is
+    /// easier for users with motor impairments and on trackpads. Dragging with the button
+    /// held keeps working as well. Escape and right-clicking cancel the drag as usual.
+    ///
+    /// While a child element is picked up, the up and down arrow keys move its drop
+    /// location by one and Enter drops it. Child elements can also be picked up
+    /// programmatically with [`grab`].
+    pub fn click_to_drag(mut self, click_to_drag: bool) -> Self {
+        self.click_to_drag = click_to_drag;
+        self
+    }
+
+    /// Sets whether moving a picked up child element with the arrow keys should wrap
+    /// around the ends of the [`Column`].
+    ///
+    /// When enabled, pressing the up arrow at the first drop location moves the child
+    /// element to the last one, and the down arrow at the last one moves it to the first.
+    /// By default, the drop location stops at the ends.
+    pub fn keyboard_wrap(mut self, keyboard_wrap: bool) -> Self {
+        self.keyboard_wrap = keyboard_wrap;
+        self
+    }
+
+    /// Sets whether up and down buttons should be shown next to each child element.
+    ///
+    /// The buttons are drawn in a gutter reserved at the end of the cross axis of the
+    /// [`Column`], and move their child element by one position, producing the same
+    /// messages as the equivalent drop, e.g. [`Column::on_drop`]. They offer a way to
+    /// reorder the child elements without dragging, for accessibility and for platforms
+    /// where dragging is awkward.
+    pub fn reorder_buttons(mut self, reorder_buttons: bool) -> Self {
+        self.reorder_buttons = reorder_buttons;
+        self
+    }
+
+    /// Makes the [`Column`] a member of the given [`DragGroup`], so that its child elements
+    /// can be dragged into the other columns of the group, and vice versa.
+    ///
+    /// Child elements of a member are dragged freely across its main axis, regardless of
+    /// [`Column::drag_lateral`], so that they can reach the other columns. A child element
+    /// following the cursor is only drawn inside of the bounds of its own column.
+    pub fn drag_group(mut self, group: &DragGroup<Key>) -> Self {
+        self.drag_group = Some(group.clone());
+        self
+    }
+
+    /// Sets the message that will be produced when a child element of another column of
+    /// the [`DragGroup`] of the [`Column`] is dropped on the [`Column`].
+    ///
+    /// The message will be produced with the key of the dropped child element and the
+    /// index of the drop position among the [`Column`] children.
+    pub fn on_transfer<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, usize) -> Message + 'a,
+    {
+        self.on_transfer = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced instead of the [`Column::on_transfer`]
+    /// message when the child element dropped on the [`Column`] carries a [`Payload`].
+    ///
+    /// The message will be produced with the payload set with [`Column::payload`] on the
+    /// source column and the index of the drop position among the [`Column`] children.
+    pub fn on_transfer_payload<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Payload, usize) -> Message + 'a,
+    {
+        self.on_transfer_payload = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the data attached to the drags of the child elements of the [`Column`].
+    ///
+    /// The data is created with the key of the dragged child element when the drag
+    /// starts, and is shared with the [`DragGroup`] of the [`Column`] as a [`Payload`],
+    /// so that the other members of the group receive data meaningful outside of the
+    /// [`Column`]. Without a group, the data is never read.
+    pub fn payload<F, T>(mut self, payload: F) -> Self
+    where
+        F: Fn(Key) -> T + 'a,
+        T: std::any::Any,
+    {
+        self.payload = Some(Box::new(move |key| Payload::new(payload(key))));
+        self
+    }
+
+    /// Sets whether the overlays of child elements, like open menus, should be hidden while
+    /// another child element is being dragged.
+    ///
+    /// If set to `false`, the overlays stay open and keep receiving events during the drag.
+    /// If set to `true`, only the overlays of the dragged child element are shown until the
+    /// drag ends, after which the overlays of its siblings are shown again.
+    pub fn hide_sibling_overlays(mut self, hide_sibling_overlays: bool) -> Self {
+        self.hide_sibling_overlays = hide_sibling_overlays;
+        self
+    }
+
+    /// Publishes a [`DropExplanation`] for a drop location decision, if
+    /// [`Column::explain_drops`] is enabled.
+    fn explain(
+        &self,
+        shell: &mut Shell<'_, Message>,
+        layout: &Layout,
+        key: Key,
+        position: Point,
+        previous: Option<usize>,
+        drop_location: usize,
+    ) {
+        let Some(on_explain) = self.on_explain.as_deref().filter(|_| self.explain_drops) else {
+            return;
+        };
+        let explanation = DropExplanation {
+            key,
+            position,
+            thresholds: layout
+                .children()
+                .map(|item_layout| flex::main_center(&Self::AXIS, item_layout.bounds()))
+                .collect(),
+            previous,
+            drop_location,
+        };
+        shell.publish(on_explain(explanation));
+    }
+
+    /// Returns whether the child element at the given index is a placeholder.
+    ///
+    /// Indices without a key, e.g. of the surplus children of a [`Column::from_vecs`]
+    /// with mismatched lengths, are not placeholders.
+    fn is_placeholder(&self, index: usize) -> bool {
+        self.placeholders.get(index).copied().unwrap_or(false)
+    }
+
+    /// Returns whether the dragged child element can be dropped at the given drop
+    /// location, i.e. next to a regular child element or right after a section header.
+    fn is_drop_location(&self, index: usize) -> bool {
+        (index > 0 && (!self.is_placeholder(index - 1) || self.sections.contains(&(index - 1))))
+            || (index < self.placeholders.len() && !self.is_placeholder(index))
+    }
+
+    /// Returns whether the child element with the given key can be dropped at the given
+    /// drop location, neither moving a [`Column::pinned`] child element nor being rejected
+    /// by [`Column::drop_range`] or [`Column::accepts_drop`].
+    fn accepts(&self, key: Key, drop_location: usize) -> bool {
+        !self.displaces_pinned(key, drop_location)
+            && self
+                .drop_range
+                .as_deref()
+                .is_none_or(|drop_range| drop_range(key).contains(&drop_location))
+            && self
+                .accepts_drop
+                .as_deref()
+                .is_none_or(|accepts_drop| accepts_drop(key, drop_location))
+    }
+
+    /// Returns whether dropping the child element with the given key at the given drop
+    /// location would move a [`Column::pinned`] child element to another index.
+    fn displaces_pinned(&self, key: Key, drop_location: usize) -> bool {
+        if self.pinned.is_empty() {
+            return false;
+        }
+        if self.pinned.contains(&key) {
+            return true;
+        }
+        let from = self.keys.iter().position(|item_key| *item_key == key);
+        let is_displaced = |index: usize| match from {
+            _ if self.drop_mode == DropMode::Swap => index == drop_location,
+            Some(from) if from < index => index < drop_location,
+            Some(from) => drop_location <= index && index < from,
+            // Transferred from another column, shifting all the children after it
+            None => drop_location <= index,
+        };
+
+        self.keys
+            .iter()
+            .enumerate()
+            .any(|(index, item_key)| self.pinned.contains(item_key) && is_displaced(index))
+    }
+
+    /// Returns the key of the header of the section containing the given drop location
+    /// and the index of the drop location within the section, if any.
+    fn section_of(&self, drop_location: usize) -> Option<(Key, usize)> {
+        let header = *self
+            .sections
+            .iter()
+            .rev()
+            .find(|header| **header < drop_location)?;
+
+        let hidden = self
+            .collapsed_header(header + 1)
+            .map_or(0, |(_, hidden)| hidden);
+
+        Some((self.keys[header], drop_location - header - 1 + hidden))
+    }
+
+    /// Returns the index of the collapsed section header right in front of the given drop
+    /// location and the number of hidden children of its section, if any.
+    fn collapsed_header(&self, drop_location: usize) -> Option<(usize, usize)> {
+        self.collapsed
+            .iter()
+            .find(|(header, _)| *header + 1 == drop_location)
+            .copied()
+    }
+
+    /// Returns the drop location of the child element with the given key at the given
+    /// position, moved to the nearest valid drop location if it lies between placeholders
+    /// or is rejected by [`Column::accepts_drop`].
+    fn drop_location(&self, layout: &Layout, key: Key, position: Point) -> usize {
+        if self.drop_mode == DropMode::Swap {
+            // The child element to swap with, skipping the section headers
+            return layout
+                .children()
+                .enumerate()
+                .filter(|(index, _)| !self.is_placeholder(*index) && self.accepts(key, *index))
+                .map(|(index, item_layout)| {
+                    let distance = flex::main_distance(&Self::AXIS, item_layout.bounds(), position);
+                    (index, distance)
+                })
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map_or(0, |(index, _)| index);
+        }
+        if let Some((header, _)) = self.collapsed.iter().find(|(header, _)| {
+            layout
+                .children()
+                .nth(*header)
+                .is_some_and(|header_layout| header_layout.bounds().contains(position))
+        }) {
+            // Dropped into the collapsed section
+            return header + 1;
+        }
+        let drop_location = flex::drop_location(&Self::AXIS, layout, position);
+        let is_valid = |index: usize| self.is_drop_location(index) && self.accepts(key, index);
+
+        if is_valid(drop_location) {
+            return drop_location;
+        }
+        (0..=self.placeholders.len())
+            .filter(|index| is_valid(*index))
+            .min_by_key(|index| index.abs_diff(drop_location))
+            .unwrap_or(drop_location)
+    }
+
+    /// Returns the keys of the child elements dropped along with the dragged child element
+    /// with the given key, in their current order.
+    fn dropped_keys(&self, key: Key) -> Vec<Key> {
+        if !self.selection.contains(&key) {
+            return vec![key];
+        }
+
+        self.keys
+            .iter()
+            .copied()
+            .filter(|item_key| self.selection.contains(item_key))
+            .collect()
+    }
+
+    /// Returns the new [`Column::selection`] after clicking the child element with the
+    /// given key with the current modifiers, if it changes, and whether the click may
+    /// start dragging.
+    fn select(&self, state: &mut State<Key>, key: Key) -> (Option<Vec<Key>>, bool) {
+        let position_of = |key: Key| self.keys.iter().position(|item_key| *item_key == key);
+
+        if state.modifiers.command() {
+            state.selection_anchor = Some(key);
+            let mut selection = self.selection.clone();
+            if let Some(index) = selection.iter().position(|selected| *selected == key) {
+                let _ = selection.remove(index);
+            } else {
+                selection.push(key);
+            }
+            (Some(selection), false)
+        } else if state.modifiers.shift() {
+            let anchor = state
+                .selection_anchor
+                .filter(|anchor| self.keys.contains(anchor))
+                .unwrap_or(key);
+            let (Some(from), Some(to)) = (position_of(anchor), position_of(key)) else {
+                return (None, false);
+            };
+            let selection = (from.min(to)..=from.max(to))
+                .filter(|index| !self.is_placeholder(*index))
+                .map(|index| self.keys[index])
+                .collect();
+            (Some(selection), false)
+        } else {
+            state.selection_anchor = Some(key);
+            let selection = (!self.selection.contains(&key)).then(|| vec![key]);
+            (selection, true)
+        }
+    }
+
+    /// Returns the [`Column::selection`] made with the given selection rectangle.
+    fn band_selection(&self, layout: &Layout, band: &RubberBand<Key>) -> Vec<Key> {
+        let bounds = band.bounds();
+        let mut selection = band.base.clone();
+        for ((key, is_placeholder), item_layout) in self
+            .keys
+            .iter()
+            .zip(&self.placeholders)
+            .zip(layout.children())
+        {
+            if !*is_placeholder
+                && item_layout.bounds().intersects(&bounds)
+                && !selection.contains(key)
+            {
+                selection.push(*key);
+            }
+        }
+        selection
+    }
+
+    /// Returns the index of the child element the dragged child element with the given key
+    /// targets at the given position with [`Column::on_drop_onto`], if any.
+    fn drop_target(&self, layout: &Layout, key: Key, position: Point) -> Option<usize> {
+        self.on_drop_onto.as_ref()?;
+
+        flex::drop_target(&Self::AXIS, layout, &self.keys, key, position, |index| {
+            !self.is_placeholder(index)
+        })
+    }
+
+    /// Returns the bounds of the drop position marker line for the given drop location.
+    fn marker_bounds(&self, layout: &Layout, drop_location: usize) -> Option<Rectangle> {
+        flex::marker_bounds(
+            &Self::AXIS,
+            layout,
+            self.content_padding(),
+            self.spacing,
+            drop_location,
+        )
+    }
+
+    /// Returns the padding of the [`Column`] including the gutter of the
+    /// [`Column::reorder_buttons`], if enabled.
+    fn content_padding(&self) -> Padding {
+        if self.reorder_buttons {
+            Padding {
+                right: self.padding.right + REORDER_BUTTONS_WIDTH,
+                ..self.padding
+            }
+        } else {
+            self.padding
+        }
+    }
+
+    /// Returns whether the [`Column::reorder_buttons`] are enabled and produce messages.
+    fn has_reorder_buttons(&self) -> bool {
+        self.reorder_buttons && self.has_drop_messages()
+    }
+
+    /// Returns whether any message is produced for a drop on the [`Column`].
+    fn has_drop_messages(&self) -> bool {
+        self.on_drop.is_some()
+            || self.on_copy_drop.is_some()
+            || self.on_reorder.is_some()
+            || self.on_drop_event.is_some()
+            || self.on_drop_anchor.is_some()
+            || self.on_section_drop.is_some()
+            || self.on_drop_position.is_some()
+            || self.on_drop_outside.is_some()
+            || self.on_swap.is_some()
+            || self.on_drop_onto.is_some()
+            || self.on_drop_selection.is_some()
+    }
+
+    /// Publishes the misconfigurations of the [`Column`] not reported yet, in debug builds.
+    fn report_errors(&self, state: &mut State<Key>, shell: &mut Shell<'_, Message>) {
+        let Some(on_error) = self.on_error.as_deref().filter(|_| cfg!(debug_assertions)) else {
+            return;
+        };
+        let children = self.children.borrow().len();
+        let errors = [
+            state
+                .duplicate
+                .map(|(first, second)| ReorderError::DuplicateKey { first, second }),
+            (self.keys.len() != children).then_some(ReorderError::KeysMismatch {
+                keys: self.keys.len(),
+                children,
+            }),
+            (self.drop_position_marker && !self.has_drop_messages() && !state.drag.is_idle())
+                .then_some(ReorderError::MissingDropMessage),
+            (self.drag_center && !self.drag_follow)
+                .then_some(ReorderError::DragCenterWithoutFollow),
+        ];
+
+        for error in errors.into_iter().flatten() {
+            if !state.reported.contains(&error) {
+                state.reported.push(error);
+                shell.publish(on_error(error));
+            }
+        }
+    }
+
+    /// Returns the bounds of the up and down [`Column::reorder_buttons`] of the child
+    /// element with the given layout.
+    fn reorder_button_bounds(
+        &self,
+        layout: &Layout,
+        item_layout: Layout<'_>,
+    ) -> (Rectangle, Rectangle) {
+        let bounds = layout.bounds();
+        let item_bounds = item_layout.bounds();
+        let up = Rectangle {
+            x: bounds.x + bounds.width - self.padding.right - REORDER_BUTTONS_WIDTH,
+            y: item_bounds.y,
+            width: REORDER_BUTTONS_WIDTH,
+            height: item_bounds.height / 2.0,
+        };
+        let down = Rectangle {
+            y: up.y + up.height,
+            ..up
+        };
+
+        (up, down)
+    }
+
+    /// Returns the index of the child element whose enabled reorder button is at the
+    /// given position, and the slot the button moves it to, if any.
+    fn reorder_button_at(&self, layout: &Layout, position: Point) -> Option<(usize, SlotIndex)> {
+        if !self.has_reorder_buttons() {
+            return None;
+        }
+        let len = self.keys.len();
+
+        layout
+            .children()
+            .zip(&self.placeholders)
+            .enumerate()
+            .filter(|(_, (_, is_placeholder))| !**is_placeholder)
+            .find_map(|(index, (item_layout, _))| {
+                let (up, down) = self.reorder_button_bounds(layout, item_layout);
+                if up.contains(position) && index > 0 {
+                    Some((index, ItemIndex(index - 1).slot_before()))
+                } else if down.contains(position) && index + 1 < len {
+                    Some((index, ItemIndex(index + 1).slot_after()))
+                } else {
+                    None
+                }
+                .filter(|(_, slot)| {
+                    self.is_drop_location(slot.0) && self.accepts(self.keys[index], slot.0)
+                })
+            })
+    }
+
+    /// Draws the enabled [`Column::reorder_buttons`] of the child elements, highlighting
+    /// the hovered or pressed one.
+    fn draw_reorder_buttons(
+        &self,
+        state: &State<Key>,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let color = theme.style(&self.class, self.status(state)).color;
+        let active = state.pressed_button.or_else(|| {
+            cursor
+                .position()
+                .filter(|_| state.drag.is_idle())
+                .and_then(|position| self.reorder_button_at(&layout, position))
+        });
+        let len = self.keys.len();
+
+        for (index, (item_layout, is_placeholder)) in
+            layout.children().zip(&self.placeholders).enumerate()
+        {
+            if *is_placeholder || !item_layout.bounds().intersects(viewport) {
+                continue;
+            }
+            let (up, down) = self.reorder_button_bounds(&layout, item_layout);
+            let buttons = [
+                (
+                    up,
+                    true,
+                    index > 0,
+                    ItemIndex(index.saturating_sub(1)).slot_before(),
+                ),
+                (
+                    down,
+                    false,
+                    index + 1 < len,
+                    ItemIndex(index + 1).slot_after(),
+                ),
+            ];
+            for (bounds, is_up, is_enabled, slot) in buttons {
+                if !is_enabled
+                    || !self.is_drop_location(slot.0)
+                    || !self.accepts(self.keys[index], slot.0)
+                {
+                    continue;
+                }
+                if active == Some((index, slot)) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            ..renderer::Quad::default()
+                        },
+                        color.scale_alpha(0.2),
+                    );
+                }
+                draw_chevron(renderer, bounds, is_up, color);
+            }
+        }
+    }
+
+    /// Publishes the messages of a drop of the child element with the given key at the
+    /// given drop location, released at the given position with the given keyboard
+    /// modifiers held.
+    fn publish_drop(
+        &self,
+        shell: &mut Shell<'_, Message>,
+        layout: &Layout,
+        key: Key,
+        drop_index: usize,
+        position: Point,
+        modifiers: keyboard::Modifiers,
+    ) {
+        if self.drop_mode == DropMode::Swap {
+            let Some(target) = self.keys.get(drop_index).filter(|target| **target != key) else {
+                return;
+            };
+            if let Some(on_drop) = self.on_drop.as_deref() {
+                shell.publish(on_drop(key, drop_index));
+            }
+            if let Some(on_swap) = self.on_swap.as_deref() {
+                shell.publish(on_swap(key, *target));
+            }
+            return;
+        }
+        if let Some(on_drop) = self.on_drop.as_deref() {
+            let message = (on_drop)(key, drop_index);
+            shell.publish(message);
+        }
+        if let Some((on_reorder, from)) = self
+            .on_reorder
+            .as_deref()
+            .zip(self.keys.iter().position(|item_key| *item_key == key))
+        {
+            let (from, slot) = (ItemIndex(from), SlotIndex(drop_index));
+            if !slot.is_noop(from) {
+                shell.publish(on_reorder(from.0, slot.target(from).0));
+            }
+        }
+        if let Some(on_drop_event) = self.on_drop_event.as_deref() {
+            let message = (on_drop_event)(DropEvent {
+                key,
+                index: SlotIndex(drop_index),
+                len: self.keys.len(),
+                revision: self.revision,
+                modifiers,
+                position,
+                bounds: layout.bounds(),
+            });
+            shell.publish(message);
+        }
+        if let Some(on_drop_anchor) = self.on_drop_anchor.as_deref() {
+            let anchor = Anchor::of(&self.keys, key, SlotIndex(drop_index));
+            shell.publish(on_drop_anchor(key, anchor));
+        }
+        if let Some((on_section_drop, (section, index))) = self
+            .on_section_drop
+            .as_deref()
+            .zip(self.section_of(drop_index))
+        {
+            shell.publish(on_section_drop(key, section, index));
+        }
+    }
+
+    /// Publishes the bounds of the drop position marker, if the drop location has changed.
+    fn publish_marker(
+        &self,
+        shell: &mut Shell<'_, Message>,
+        layout: &Layout,
+        previous: Option<usize>,
+        drop_location: Option<usize>,
+    ) {
+        if let Some(on_marker) = self.on_marker.as_deref() {
+            if drop_location != previous {
+                let bounds = drop_location
+                    .and_then(|drop_location| self.marker_bounds(layout, drop_location));
+                shell.publish(on_marker(bounds));
+            }
+        }
+    }
+
+    /// Measures up to `children_per_frame` more child elements for the initial
+    /// [`Column::incremental_layout`], and stacks all the child elements measured so far.
+    ///
+    /// The height of the child elements not measured yet is estimated from the measured
+    /// ones, so that the [`Column`] keeps roughly its final size meanwhile.
+    fn layout_incrementally(
+        &self,
+        state: &mut State<Key>,
+        trees: &mut [Tree],
+        renderer: &Renderer,
+        limits: &layout::Limits,
+        children_per_frame: usize,
+    ) -> layout::Node {
+        let children = self.children.borrow();
+        let padding = self.content_padding();
+        let cross_space = limits.max().width - padding.horizontal();
+        let child_limits = layout::Limits::new(Size::ZERO, Size::new(cross_space, f32::INFINITY));
+
+        let measured = state.measured.len();
+        state.measured.extend(
+            children
+                .iter()
+                .zip(trees)
+                .skip(measured)
+                .take(children_per_frame)
+                .map(|(child, child_tree)| {
+                    child
+                        .as_widget()
+                        .layout(child_tree, renderer, &child_limits)
+                }),
+        );
+        let is_complete = state.measured.len() >= children.len();
+
+        let mut y = padding.top;
+        let mut content_width: f32 = 0.0;
+        let nodes: Vec<layout::Node> = state
+            .measured
+            .iter()
+            .map(|child_node| {
+                let size = child_node.size();
+                let x = padding.left
+                    + match self.align {
+                        Alignment::Start => 0.0,
+                        Alignment::Center => (cross_space - size.width) * 0.5,
+                        Alignment::End => cross_space - size.width,
+                    };
+                let child_node = child_node.clone().move_to(Point::new(x, y));
+
+                y += size.height + self.spacing;
+                content_width = content_width.max(size.width);
+                child_node
+            })
+            .collect();
+
+        let remaining = children.len() - nodes.len();
+        let estimate = if nodes.is_empty() {
+            0.0
+        } else {
+            (y - padding.top) / nodes.len() as f32
+        };
+        let content_height = if nodes.is_empty() {
+            padding.vertical()
+        } else {
+            y + remaining as f32 * estimate - self.spacing + padding.bottom
+        };
+        let size = limits.resolve(
+            self.lengths().width,
+            self.lengths().height,
+            Size::new(content_width + padding.horizontal(), content_height),
+        );
+
+        if is_complete {
+            state.measured = Vec::new();
+            state.layout_complete = true;
+        }
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    /// Draws the placeholder rows of the child elements not measured yet by the initial
+    /// [`Column::incremental_layout`].
+    fn draw_pending_rows(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        status: Status,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) {
+        let measured = layout.children().count();
+        let Some(last) = layout
+            .children()
+            .last()
+            .map(|item_layout| item_layout.bounds())
+        else {
+            return;
+        };
+        let bounds = layout.bounds();
+        let padding = self.content_padding();
+        let height = (last.y + last.height - bounds.y - padding.top + self.spacing)
+            / measured as f32
+            - self.spacing;
+        let color = theme.style(&self.class, status).color.scale_alpha(0.1);
+
+        for row in 0..self.keys.len().saturating_sub(measured) {
+            let row_bounds = Rectangle {
+                x: bounds.x + padding.left,
+                y: last.y + last.height + self.spacing + row as f32 * (height + self.spacing),
+                width: bounds.width - padding.horizontal(),
+                height,
+            };
+            if row_bounds.y > viewport.y + viewport.height {
+                break;
+            }
+            if row_bounds.intersects(viewport) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border: Border {
+                            radius: 2.0.into(),
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+        }
+    }
+
+    /// Lays out the [`Column::push_static`] elements in the flow, moving the keyed child
+    /// elements after each of them out of its way.
+    ///
+    /// The layouts of the static elements are returned separately to be kept in the
+    /// [`State`], so that the children of the [`Column`] layout remain the keyed child
+    /// elements.
+    fn layout_statics(
+        &self,
+        node: layout::Node,
+        trees: &mut [Tree],
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> (layout::Node, Vec<layout::Node>) {
+        if self.statics.is_empty() {
+            return (node, Vec::new());
+        }
+        let padding = self.content_padding();
+        let cross_space = node.size().width - padding.horizontal();
+        let child_limits = layout::Limits::new(Size::ZERO, Size::new(cross_space, f32::INFINITY));
+        let static_trees = trees.iter_mut().skip(self.keys.len());
+        let mut statics = self.statics.iter().zip(static_trees).peekable();
+
+        let mut shift = 0.0;
+        let mut end = padding.top;
+        let mut nodes = Vec::with_capacity(node.children().len());
+        let mut static_nodes = Vec::with_capacity(self.statics.len());
+        for index in 0..=node.children().len() {
+            let child_node = node.children().get(index);
+            let y = child_node.map_or(end, |child_node| child_node.bounds().y);
+            while let Some(((_, child), child_tree)) =
+                statics.next_if(|((slot, _), _)| *slot == index)
+            {
+                let static_node = child
+                    .as_widget()
+                    .layout(child_tree, renderer, &child_limits);
+                let size = static_node.size();
+                let x = padding.left
+                    + match self.align {
+                        Alignment::Start => 0.0,
+                        Alignment::Center => (cross_space - size.width) * 0.5,
+                        Alignment::End => cross_space - size.width,
+                    };
+                static_nodes.push(static_node.move_to(Point::new(x, y + shift)));
+                shift += size.height + self.spacing;
+            }
+            if let Some(child_node) = child_node {
+                let bounds = child_node.bounds();
+                end = bounds.y + bounds.height + self.spacing;
+                nodes.push(child_node.clone().translate(Vector::new(0.0, shift)));
+            }
+        }
+        let size = limits.resolve(
+            self.lengths().width,
+            self.lengths().height,
+            Size::new(node.size().width, node.size().height + shift),
+        );
+        (layout::Node::with_children(size, nodes), static_nodes)
+    }
+
+    /// Returns the [`Column::push_static`] elements with their trees and layouts.
+    fn static_layouts<'b>(
+        &'b self,
+        trees: &'b [Tree],
+        static_nodes: &'b [layout::Node],
+        layout: Layout<'b>,
+    ) -> impl Iterator<
+        Item = (
+            &'b Element<'a, Message, Theme, Renderer>,
+            &'b Tree,
+            Layout<'b>,
+        ),
+    > {
+        let offset = layout.position() - Point::ORIGIN;
+
+        self.statics
+            .iter()
+            .zip(trees.iter().skip(self.keys.len()))
+            .zip(static_nodes)
+            .map(move |(((_, child), child_tree), static_node)| {
+                (child, child_tree, Layout::with_offset(offset, static_node))
+            })
+    }
+
+    /// Lays out again the child elements taller than [`Column::item_max_height`] with
+    /// the maximum height as a limit, and restacks all child elements accordingly.
+    ///
+    /// The space freed by the clamped child elements goes to the child elements with a
+    /// [`Length::Fill`] or [`Length::FillPortion`] height, which share it by their fill
+    /// factors without exceeding the maximum height either. Only the child elements whose
+    /// height changes are laid out again.
+    fn clamp_item_heights(
+        &self,
+        node: layout::Node,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = self.content_padding();
+        let cross_space = node.size().width - padding.horizontal();
+        let max_height = self.item_max_height;
+        let children = self.children.borrow();
+        let layout_child =
+            |child: &Element<'a, Message, Theme, Renderer>, state: &mut Tree, height: f32| {
+                let child_limits = layout::Limits::new(Size::ZERO, Size::new(cross_space, height));
+                child.as_widget().layout(state, renderer, &child_limits)
+            };
+
+        // Clamp the child elements not filling the column first, freeing their space for
+        // the filling ones
+        let mut fill_space = 0.0;
+        let mut fills = Vec::new();
+        let mut nodes: Vec<layout::Node> = node
+            .children()
+            .iter()
+            .zip(children.iter())
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(index, ((child_node, child), state))| {
+                let height = child_node.size().height;
+                let fill_factor = child.as_widget().size().height.fill_factor();
+                if fill_factor > 0 {
+                    fill_space += height;
+                    fills.push((index, f32::from(fill_factor)));
+                    child_node.clone()
+                } else if height > max_height {
+                    let clamped_node = layout_child(child, state, max_height);
+                    fill_space += height - clamped_node.size().height.min(max_height);
+                    clamped_node
+                } else {
+                    child_node.clone()
+                }
+            })
+            .collect();
+
+        // Share the space among the filling child elements by their fill factors, until
+        // none of the shares exceeds the maximum height
+        let mut is_clamped = vec![false; fills.len()];
+        let (space, factors) = loop {
+            let clamped_count = is_clamped.iter().filter(|is_clamped| **is_clamped).count();
+            let space = fill_space - max_height * clamped_count as f32;
+            let factors: f32 = fills
+                .iter()
+                .zip(&is_clamped)
+                .filter(|(_, is_clamped)| !**is_clamped)
+                .map(|((_, fill_factor), _)| fill_factor)
+                .sum();
+            let mut is_changed = false;
+            for ((_, fill_factor), is_clamped) in fills.iter().zip(&mut is_clamped) {
+                if !*is_clamped && space * fill_factor / factors > max_height {
+                    *is_clamped = true;
+                    is_changed = true;
+                }
+            }
+            if !is_changed {
+                break (space, factors);
+            }
+        };
+        for ((index, fill_factor), is_clamped) in fills.iter().zip(&is_clamped) {
+            let height = if *is_clamped {
+                max_height
+            } else {
+                space * fill_factor / factors
+            };
+            if (nodes[*index].size().height - height).abs() > 0.5 {
+                nodes[*index] = layout_child(&children[*index], &mut tree.children[*index], height);
+            }
+        }
+
+        let mut y = padding.top;
+        let mut content_width: f32 = 0.0;
+        let nodes: Vec<layout::Node> = nodes
+            .into_iter()
+            .map(|child_node| {
+                let size = child_node.size();
+                let x = padding.left
+                    + match self.align {
+                        Alignment::Start => 0.0,
+                        Alignment::Center => (cross_space - size.width) * 0.5,
+                        Alignment::End => cross_space - size.width,
+                    };
+                let child_node = child_node.move_to(Point::new(x, y));
+
+                y += size.height.min(max_height) + self.spacing;
+                content_width = content_width.max(size.width);
+                child_node
+            })
+            .collect();
+
+        let content_height = if nodes.is_empty() {
+            padding.vertical()
+        } else {
+            y - self.spacing + padding.bottom
+        };
+        let size = limits.resolve(
+            self.lengths().width,
+            self.lengths().height,
+            Size::new(content_width + padding.horizontal(), content_height),
+        );
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    /// Builds the child elements of the keys given to [`Column::lazy`] that are not built
+    /// yet.
+    fn build_lazy_children(&self) {
+        let Some(lazy_view) = &self.lazy_view else {
+            return;
+        };
+        let mut children = self.children.borrow_mut();
+        if children.len() < self.keys.len() {
+            let built = children.len();
+            let mut size = lazy_view.size.get();
+            children.extend(self.keys[built..].iter().map(|key| {
+                let child = (lazy_view.view)(*key);
+                let child_size = child.as_widget().size_hint();
+                size = Size::new(
+                    size.width.enclose(child_size.width),
+                    size.height.enclose(child_size.height),
+                );
+                child
+            }));
+            lazy_view.size.set(size);
+        }
+    }
+
+    /// Returns the sizing strategy of the [`Column`], adapted to the child elements built
+    /// by the view function of [`Column::lazy`].
+    fn lengths(&self) -> Size<Length> {
+        let lazy_size = self
+            .lazy_view
+            .as_ref()
+            .map(|lazy_view| lazy_view.size.get())
+            .unwrap_or(Size::new(Length::Shrink, Length::Shrink));
+
+        Size::new(
+            self.width.enclose(lazy_size.width),
+            self.height.enclose(lazy_size.height),
+        )
+    }
+
+    /// Rebuilds the child elements created with [`Column::with_children_status`] whose
+    /// [`ItemStatus`] differs from the given drag state.
+    ///
+    /// Returns whether any child element was rebuilt.
+    fn refresh_status_children(&self, drag: &DragState<Key>) -> bool {
+        let Some(status_view) = &self.status_view else {
+            return false;
+        };
+        let mut statuses = status_view.statuses.borrow_mut();
+        let mut children = self.children.borrow_mut();
+        let mut refreshed = false;
+
+        for ((key, status), child) in self
+            .keys
+            .iter()
+            .zip(statuses.iter_mut())
+            .zip(children.iter_mut())
+        {
+            let new_status = drag.item_status(*key);
+            if *status != new_status {
+                *status = new_status;
+                *child = (status_view.view)(*key, new_status);
+                refreshed = true;
+            }
+        }
+        refreshed
+    }
+
+    /// Moves the dragged child element to the given position, publishing the drag messages
+    /// for the resolved drop location.
+    fn drag_to(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: &Layout,
+        (key, origin, offset): (Key, Point, Vector),
+        position: Point,
+    ) {
+        if !matches!(state.drag, DragState::Dragged { .. }) {
+            state.outside = false;
+        }
+        let is_outside = !layout.bounds().contains(position);
+        if is_outside != state.outside {
+            state.outside = is_outside;
+            let on_crossing = if is_outside {
+                &self.on_drag_exit
+            } else {
+                &self.on_drag_enter
+            };
+            if let Some(on_crossing) = on_crossing {
+                shell.publish(on_crossing(key));
+            }
+        }
+        if is_outside && self.drag_exit == DragExit::Cancel {
+            self.publish_marker(shell, layout, state.drag.drop_location(), None);
+            if let Some(group) = &self.drag_group {
+                group.cancel();
+            }
+            if let Some(on_cancel) = &self.on_cancel {
+                shell.publish(on_cancel(key));
+            }
+            state.drag = DragState::Idle;
+            state.picked_up = false;
+            state.pending_position = None;
+            shell.request_redraw();
+            return;
+        }
+        let drop_location = self.drop_location(layout, key, position);
+        let previous_target = state
+            .drag
+            .last_position()
+            .and_then(|previous| self.drop_target(layout, key, previous));
+        if Some(drop_location) != state.drag.drop_location()
+            || self.drop_target(layout, key, position) != previous_target
+        {
+            shell.request_redraw();
+        }
+        self.explain(
+            shell,
+            layout,
+            key,
+            position,
+            state.drag.drop_location(),
+            drop_location,
+        );
+        if let Some(on_drag) = self.on_drag.as_deref() {
+            if Some(drop_location) != state.drag.drop_location() {
+                let message = (on_drag)(key, drop_location);
+                shell.publish(message);
+            }
+        }
+        self.publish_marker(
+            shell,
+            layout,
+            state.drag.drop_location(),
+            Some(drop_location),
+        );
+        state.drag = DragState::Dragged {
+            key,
+            origin,
+            offset,
+            position,
+            drop_location,
+        };
+    }
+
+    /// Picks up the child element with the given key as if it had been clicked with
+    /// [`Column::click_to_drag`] enabled, so that the next press drops it.
+    fn pick_up(&self, state: &mut State<Key>, layout: &Layout, key: Key) {
+        let Some((_, item_layout)) = self
+            .keys
+            .iter()
+            .zip(&self.placeholders)
+            .zip(layout.children())
+            .find(|((item_key, is_placeholder), _)| {
+                **item_key == key && !**is_placeholder && !self.pinned.contains(item_key)
+            })
+        else {
+            return;
+        };
+        let bounds = item_layout.bounds();
+        let origin = bounds.center();
+
+        state.drag = DragState::Dragged {
+            key,
+            origin,
+            offset: origin - bounds.position(),
+            position: origin,
+            drop_location: self.drop_location(layout, key, origin),
+        };
+        state.picked_up = true;
+        state.pending_position = None;
+        self.share_drag(state, key);
+    }
+
+    /// Publishes a [`Column::on_auto_scroll`] message scrolling the drop location of the
+    /// dragged child element into the `viewport`, if it has been moved out of it with the
+    /// keyboard.
+    fn reveal_drop_location(
+        &self,
+        state: &State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: &Layout,
+        viewport: &Rectangle,
+    ) {
+        let (Some(on_auto_scroll), Some(key), Some(drop_location)) = (
+            self.on_auto_scroll.as_deref(),
+            state.drag.key(),
+            state.drag.drop_location(),
+        ) else {
+            return;
+        };
+        let Some(dragged) = self
+            .keys
+            .iter()
+            .position(|item_key| *item_key == key)
+            .and_then(|index| layout.children().nth(index))
+        else {
+            return;
+        };
+        let target = flex::slot_bounds(
+            &Self::AXIS,
+            layout.children().map(|item_layout| item_layout.bounds()),
+            dragged.bounds(),
+            drop_location,
+        );
+        if let Some(delta) = flex::reveal_delta(&Self::AXIS, target, viewport) {
+            shell.publish(on_auto_scroll(delta));
+        }
+    }
+
+    /// Shares the drag of the child element with the given key with the [`DragGroup`] of
+    /// the [`Column`], if any.
+    fn share_drag(&self, state: &mut State<Key>, key: Key) {
+        if let Some(group) = &self.drag_group {
+            let member = *state.member.get_or_insert_with(|| group.join());
+            group.start(
+                key,
+                member,
+                self.payload.as_ref().map(|payload| payload(key)),
+            );
+        }
+    }
+
+    /// Updates the drag of a child element from another column of the [`DragGroup`] of the
+    /// [`Column`] hovering the [`Column`] at the given position, if any.
+    fn receive_drag(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: &Layout,
+        position: Option<Point>,
+    ) {
+        let Some(group) = &self.drag_group else {
+            return;
+        };
+        let member = *state.member.get_or_insert_with(|| group.join());
+        let incoming = group
+            .drag()
+            .filter(|drag| drag.source != member)
+            .zip(position.filter(|position| layout.bounds().contains(*position)))
+            .map(|(drag, position)| {
+                let drop_location = self.drop_location(layout, drag.key, position);
+                (drag.id, drag.key, drop_location)
+            });
+
+        if incoming != state.incoming {
+            group.hover(member, incoming.is_some());
+            state.incoming = incoming;
+            shell.request_redraw();
+        }
+    }
+
+    /// Publishes the [`Column::on_click`] and [`Column::on_double_click`] messages for the
+    /// release of the child element with the given key, if it is released over it.
+    fn click(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: &Layout,
+        key: Key,
+        cursor: mouse::Cursor,
+    ) {
+        let is_over = self
+            .keys
+            .iter()
+            .zip(layout.children())
+            .any(|(item_key, item_layout)| {
+                *item_key == key && cursor.is_over(item_layout.bounds())
+            });
+        if !is_over {
+            return;
+        }
+        if let Some(on_click) = &self.on_click {
+            shell.publish(on_click(key));
+        }
+        let now = self.clock.now();
+        let is_double = state.last_click.is_some_and(|(last_key, at)| {
+            last_key == key && now.saturating_duration_since(at) <= DOUBLE_CLICK_INTERVAL
+        });
+        if is_double {
+            if let Some(on_double_click) = &self.on_double_click {
+                shell.publish(on_double_click(key));
+            }
+            state.last_click = None;
+        } else {
+            state.last_click = Some((key, now));
+        }
+    }
+
+    /// Publishes the [`Column::on_hover`] and [`Column::on_hover_exit`] messages, if the
+    /// cursor moved onto another child element.
+    fn hover(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: &Layout,
+        cursor: mouse::Cursor,
+    ) {
+        if self.on_hover.is_none() && self.on_hover_exit.is_none() {
+            return;
+        }
+        let hovered = self
+            .keys
+            .iter()
+            .zip(layout.children())
+            .find(|(_, item_layout)| cursor.is_over(item_layout.bounds()))
+            .map(|(key, _)| *key);
+        if hovered == state.hovered {
+            return;
+        }
+        if let Some((on_hover_exit, key)) = self.on_hover_exit.as_deref().zip(state.hovered) {
+            shell.publish(on_hover_exit(key));
+        }
+        if let Some((on_hover, key)) = self.on_hover.as_deref().zip(hovered) {
+            shell.publish(on_hover(key));
+        }
+        state.hovered = hovered;
+    }
+
+    /// Drops the dragged child element at the given position, ending the drag.
+    fn drop_at(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: &Layout,
+        (key, previous): (Key, usize),
+        position: Point,
+    ) {
+        let is_outside = !layout.bounds().contains(position);
+        if let Some(on_drop_outside) = self.on_drop_outside.as_deref().filter(|_| {
+            is_outside
+                && self
+                    .drag_group
+                    .as_ref()
+                    .is_none_or(|group| group.target().is_none())
+        }) {
+            if let Some(group) = &self.drag_group {
+                group.cancel();
+            }
+            shell.publish(on_drop_outside(key, position));
+            self.publish_marker(shell, layout, Some(previous), None);
+            state.drag = DragState::Idle;
+            state.picked_up = false;
+            shell.capture_event();
+            return;
+        }
+        if let Some(group) = self
+            .drag_group
+            .as_ref()
+            .filter(|_| !layout.bounds().contains(position))
+        {
+            // Dropped outside of the column: into another member of the group, or nowhere
+            if group.target().is_some() {
+                group.release();
+            } else {
+                group.cancel();
+                if let Some(on_cancel) = &self.on_cancel {
+                    shell.publish(on_cancel(key));
+                }
+            }
+            self.publish_marker(shell, layout, Some(previous), None);
+            state.drag = DragState::Idle;
+            state.picked_up = false;
+            shell.capture_event();
+            return;
+        }
+        if let Some((on_drop_onto, target)) = self
+            .on_drop_onto
+            .as_deref()
+            .zip(self.drop_target(layout, key, position))
+        {
+            shell.publish(on_drop_onto(key, self.keys[target]));
+        } else if let Some(on_drop_position) = self.on_drop_position.as_deref() {
+            let offset = match state.drag {
+                DragState::Grabbed { offset, .. } | DragState::Dragged { offset, .. } => offset,
+                DragState::Idle => Vector::ZERO,
+            };
+            let relative = (position - offset) - layout.bounds().position();
+            shell.publish(on_drop_position(key, Point::new(relative.x, relative.y)));
+        } else {
+            let drop_index = self.drop_location(layout, key, position);
+            if !self.accepts(key, drop_index) {
+                // No drop location is accepted
+                if let Some(on_cancel) = &self.on_cancel {
+                    shell.publish(on_cancel(key));
+                }
+            } else {
+                self.explain(shell, layout, key, position, Some(previous), drop_index);
+                if let Some(on_copy_drop) = self
+                    .on_copy_drop
+                    .as_deref()
+                    .filter(|_| state.modifiers.control() && self.drop_mode == DropMode::Insert)
+                {
+                    shell.publish(on_copy_drop(key, drop_index));
+                } else if let Some(on_drop_selection) = self.on_drop_selection.as_deref() {
+                    shell.publish(on_drop_selection(self.dropped_keys(key), drop_index));
+                } else {
+                    self.publish_drop(shell, layout, key, drop_index, position, state.modifiers);
+                }
+            }
+        }
+        self.publish_marker(shell, layout, Some(previous), None);
+        // Let the floating child element settle into its slot instead of jumping there
+        state.settle = self
+            .dragged_translation(state, *layout)
+            .and_then(|(key, translation)| {
+                let index = self.keys.iter().position(|item_key| *item_key == key)?;
+                let item_layout = layout.children().nth(index)?;
+
+                Some(Settle {
+                    key,
+                    from: (item_layout.bounds().position() - layout.bounds().position())
+                        + translation,
+                    start: self.clock.now(),
+                })
+            });
+        state.drag = DragState::Idle;
+        state.picked_up = false;
+        shell.capture_event();
+    }
+
+    /// Returns the [`Status`] of the [`Column`] in the given state, styling it with its
+    /// theme.
+    fn status(&self, state: &State<Key>) -> Status {
+        match state.drag {
+            DragState::Dragged {
+                key, drop_location, ..
+            } if !self.accepts(key, drop_location) => Status::Invalid,
+            drag => drag.status(),
+        }
+    }
+
+    /// Returns whether the dragged child element can leave the bounds of the [`Column`],
+    /// i.e. it is a member of a [`DragGroup`] or leaving is handled with
+    /// [`Column::on_drop_outside`], [`Column::drag_exit`] or [`Column::on_drag_exit`].
+    fn can_leave(&self) -> bool {
+        self.drag_group.is_some()
+            || self.on_drop_outside.is_some()
+            || self.drag_exit == DragExit::Cancel
+            || self.on_drag_exit.is_some()
+    }
+
+    /// Returns the given drag position constrained to the main axis relative to the drag
+    /// `origin`, unless [`Column::drag_lateral`] is enabled, the dragged child element can
+    /// leave the [`Column`] or it drops at free positions with [`Column::on_drop_position`].
+    fn constrain(&self, position: Point, origin: Point) -> Point {
+        if self.drag_lateral || self.can_leave() || self.on_drop_position.is_some() {
+            position
+        } else {
+            flex::lock_cross(&Self::AXIS, position, origin)
+        }
+    }
+
+    /// Returns whether a release at the given position completes a click picking up the
+    /// child element grabbed at `origin`, if [`Column::click_to_drag`] is enabled.
+    fn is_click(&self, position: Point, origin: Point) -> bool {
+        self.click_to_drag && position.distance(origin) <= TAP_TOLERANCE
+    }
+
+    /// Returns the index of the dragged child element, the index it ends up at when
+    /// dropped, and its extent along the main axis including the spacing, if its siblings
+    /// are displaced to make room for it.
+    fn displacement(&self, state: &State<Key>, layout: Layout<'_>) -> Option<(usize, usize, f32)> {
+        let DragState::Dragged {
+            key,
+            position,
+            drop_location,
+            ..
+        } = state.drag
+        else {
+            return None;
+        };
+        if matches!(
+            self.presentation,
+            Presentation::Marker | Presentation::GapHighlight
+        ) || self.drop_mode == DropMode::Swap
+            || self.on_drop_position.is_some()
+            || (self.can_leave() && !layout.bounds().contains(position))
+        {
+            return None;
+        }
+        let from = self.keys.iter().position(|item_key| *item_key == key)?;
+        let extent = layout.children().nth(from)?.bounds().height + self.spacing;
+
+        Some((
+            from,
+            SlotIndex(drop_location).target(ItemIndex(from)).0,
+            extent,
+        ))
+    }
+
+    /// Returns the [`DragAnimator`] of the settling of a dropped child element, which is
+    /// the [`Column::animator`] if set.
+    fn settle_animator(&self) -> &dyn DragAnimator {
+        const DEFAULT: Timed = Timed {
+            duration: Duration::from_millis(150),
+            easing: Easing::EaseOut,
+        };
+
+        self.animator.as_deref().unwrap_or(&DEFAULT)
+    }
+
+    /// Returns the key of the dropped child element settling into its slot and its
+    /// translation from its laid out position, if it is still settling at `now`.
+    fn settle_translation(
+        &self,
+        state: &State<Key>,
+        layout: Layout<'_>,
+        now: Instant,
+    ) -> Option<(Key, Vector)> {
+        let settle = state.settle.as_ref()?;
+        let animator = self.settle_animator();
+        if !animator.is_running(settle.start, now) {
+            return None;
+        }
+        let index = self.keys.iter().position(|key| *key == settle.key)?;
+        let target = layout.children().nth(index)?.bounds().position() - layout.bounds().position();
+        let progress = animator.progress(settle.start, now);
+
+        Some((settle.key, (settle.from - target) * (1.0 - progress)))
+    }
+
+    /// Returns the key of the child element floating above its siblings and its
+    /// translation from its laid out position: the one following the cursor, or the one
+    /// settling into its slot after being dropped.
+    fn floating_translation(
+        &self,
+        state: &State<Key>,
+        layout: Layout<'_>,
+    ) -> Option<(Key, Vector)> {
+        self.dragged_translation(state, layout)
+            .or_else(|| self.settle_translation(state, layout, self.clock.now()))
+    }
+
+    /// Advances the [`Column::animator`] motions of the child elements towards their
+    /// current positions, returning whether any of them is still running.
+    fn advance_motions(&self, state: &mut State<Key>, layout: Layout<'_>, now: Instant) -> bool {
+        let Some(animator) = self.animator.as_deref() else {
+            return false;
+        };
+        let origin = layout.bounds().position();
+        let displacement = self.displacement(state, layout);
+        let follow_key = self.dragged_translation(state, layout).map(|(key, _)| key);
+        let settle_key = state.settle.as_ref().map(|settle| settle.key);
+
+        let mut running = false;
+        let motions = self
+            .keys
+            .iter()
+            .zip(layout.children())
+            .enumerate()
+            .map(|(index, (key, item_layout))| {
+                let translation = displacement.map_or(Vector::ZERO, |displacement| {
+                    self.displaced(layout, index, displacement)
+                });
+                let target = (item_layout.bounds().position() - origin) + translation;
+                let previous = state
+                    .motions
+                    .get(index)
+                    .filter(|motion| motion.key == *key)
+                    .or_else(|| state.motions.iter().find(|motion| motion.key == *key));
+
+                let motion = match previous {
+                    Some(motion) if motion.target == target => *motion,
+                    Some(motion) if Some(*key) != follow_key && Some(*key) != settle_key => {
+                        Motion {
+                            key: *key,
+                            from: motion.position(animator, now),
+                            target,
+                            start: now,
+                        }
+                    }
+                    _ => Motion {
+                        key: *key,
+                        from: target,
+                        target,
+                        start: now,
+                    },
+                };
+                running |= motion.from != motion.target && animator.is_running(motion.start, now);
+                motion
+            })
+            .collect();
+
+        state.motions = motions;
+        running
+    }
+
+    /// Returns the translations of the child elements from their laid out positions to
+    /// their animated positions, if the [`Column::animator`] is set.
+    fn motion_translations(&self, state: &State<Key>, layout: Layout<'_>) -> Option<Vec<Vector>> {
+        let animator = self.animator.as_deref()?;
+        let origin = layout.bounds().position();
+        let now = self.clock.now();
+
+        Some(
+            self.keys
+                .iter()
+                .zip(layout.children())
+                .enumerate()
+                .map(|(index, (key, item_layout))| {
+                    let laid_out = item_layout.bounds().position() - origin;
+                    state
+                        .motions
+                        .get(index)
+                        .filter(|motion| motion.key == *key)
+                        .map_or(Vector::ZERO, |motion| {
+                            motion.position(animator, now) - laid_out
+                        })
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the translation of the child element at `index` for the given
+    /// [`Column::displacement`], moving the siblings between the origin and the
+    /// destination of the dragged child element out of its way.
+    ///
+    /// The dragged child element itself is moved to the room left at its destination.
+    fn displaced(
+        &self,
+        layout: Layout<'_>,
+        index: usize,
+        (from, to, extent): (usize, usize, f32),
+    ) -> Vector {
+        let bounds = |index| {
+            layout
+                .children()
+                .nth(index)
+                .map(|item_layout: Layout<'_>| item_layout.bounds())
+                .unwrap_or_default()
+        };
+
+        let y = if index == from {
+            let origin = bounds(from);
+            let target = bounds(to);
+            if to > from {
+                target.y + target.height - origin.height - origin.y
+            } else {
+                target.y - origin.y
+            }
+        } else if from < index && index <= to {
+            -extent
+        } else if to <= index && index < from {
+            extent
+        } else {
+            0.0
+        };
+
+        Vector::new(0.0, y)
+    }
+
+    /// Returns the bounds of the [`DragHandle`]s in the child element at `index`.
+    fn drag_handles(
+        &self,
+        index: usize,
+        tree: &mut Tree,
+        item_layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Vec<Rectangle> {
+        let mut find_handles = drag_handle::FindHandles::default();
+        if let Some(child) = self.children.borrow().get(index) {
+            child
+                .as_widget()
+                .operate(tree, item_layout, renderer, &mut find_handles);
+        }
+
+        find_handles.bounds
+    }
+
+    /// Returns whether the cursor is over an interactive widget of the child element at
+    /// `index`, i.e. a widget showing a mouse interaction other than grabbing.
+    fn is_interactive(
+        &self,
+        index: usize,
+        tree: &Tree,
+        item_layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> bool {
+        self.children.borrow().get(index).is_some_and(|child| {
+            !matches!(
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, item_layout, cursor, viewport, renderer),
+                mouse::Interaction::None
+                    | mouse::Interaction::Idle
+                    | mouse::Interaction::Grab
+                    | mouse::Interaction::Grabbing
+            )
+        })
+    }
+
+    /// Returns the key of the child element following the cursor and its translation
+    /// from its laid out position, if any.
+    fn dragged_translation(&self, state: &State<Key>, layout: Layout<'_>) -> Option<(Key, Vector)> {
+        state
+            .drag
+            .follow_translation(self.keys.iter().zip(layout.children()))
+            .filter(|_| self.drag_follow)
+    }
+}
+
+impl<'a, Key, Meta, Message, Theme, Renderer>
+    Column<'a, WithMeta<Key, Meta>, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Meta: Copy,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Adds an element to the [`Column`], associating the given metadata with its key.
+    ///
+    /// The callbacks receive the [`WithMeta`] of the child element, carrying both the key
+    /// and the metadata.
+    pub fn push_with_meta(
+        self,
+        key: Key,
+        meta: Meta,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.push(WithMeta::new(key, meta), child)
+    }
+}
+
+/// Creates a new [`Column`] with the given keyed children.
+///
+/// Use [`reorderable_column!`] to list the children inline instead.
+pub fn column<'a, Key, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+) -> Column<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    Column::with_children(children)
+}
+
+/// Produces a [`Task`] that scrolls the innermost scrollable containing the [`Column`]
+/// child element with the given key, so that the child element becomes fully visible.
+///
+/// See [`operation::reveal`] for details.
+pub fn reveal<Key, T>(key: Key) -> Task<T>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    iced::advanced::widget::operate(operation::reveal(key))
+}
+
+/// Produces a [`Task`] that scrolls the innermost scrollable containing the [`Column`]
+/// with the given [`Id`], so that its child element with the given key is centered in
+/// view.
+///
+/// See [`operation::scroll_to`] for details.
+pub fn scroll_to<Key, T>(id: impl Into<Id>, key: Key) -> Task<T>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    iced::advanced::widget::operate(operation::scroll_to(id.into(), key))
+}
+
+/// Produces a [`Task`] that scrolls the innermost scrollable containing the [`Column`]
+/// with the given [`Id`] by the given amount.
+///
+/// See [`operation::scroll_by`] for details.
+pub fn scroll_by<T>(id: impl Into<Id>, delta: Vector) -> Task<T>
+where
+    T: Send + 'static,
+{
+    iced::advanced::widget::operate(operation::scroll_by(id.into(), delta))
+}
+
+/// Produces a [`Task`] that picks up the child element with the given key of the
+/// [`Column`] with the given [`Id`].
+///
+/// See [`operation::grab`] for details.
+pub fn grab<Key, T>(id: impl Into<Id>, key: Key) -> Task<T>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    iced::advanced::widget::operate(operation::grab(id.into(), key))
+}
+
+/// Produces a [`Task`] that focuses the child element with the given key of the
+/// [`Column`] with the given [`Id`].
+///
+/// See [`operation::focus`] for details.
+pub fn focus<Key, T>(id: impl Into<Id>, key: Key) -> Task<T>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    iced::advanced::widget::operate(operation::focus(id.into(), key))
+}
+
+/// Produces a [`Task`] that resolves to the [`Geometry`](operation::Geometry) of the
+/// children of the [`Column`] with the given [`Id`].
+///
+/// See [`operation::geometry`] for details.
+pub fn geometry<Key>(id: impl Into<Id>) -> Task<operation::Geometry<Key>>
+where
+    Key: Copy + Send + 'static,
+{
+    iced::advanced::widget::operate(operation::geometry(id.into()))
+}
+
+/// Produces a [`Task`] that resolves to the drag in progress on the [`Column`] with the
+/// given [`Id`], or `None` if it is idle.
+///
+/// See [`operation::drag_state`] for details.
+pub fn drag_state<Key>(id: impl Into<Id>) -> Task<Option<operation::DragInfo<Key>>>
+where
+    Key: Copy + Send + 'static,
+{
+    iced::advanced::widget::operate(operation::drag_state(id.into()))
+}
+
+/// Creates a [`Column`] with the given keyed children.
+///
+/// Each child is given as `key => element`, where the element can be anything that
+/// converts into an [`Element`].
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::text;
+/// use iced_reorderable::reorderable_column;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(&'static str, usize),
+/// }
+///
+/// fn view() -> iced::Element<'static, Message> {
+///     reorderable_column![
+///         "first" => text("First item"),
+///         "second" => text("Second item"),
+///     ]
+///     .on_drop(Message::Dropped)
+///     .into()
+/// }
+/// ```
+#[macro_export]
+macro_rules! reorderable_column {
+    () => (
+        $crate::Column::new()
+    );
+    ($($key:expr => $child:expr),+ $(,)?) => (
+        $crate::Column::new()$(.push($key, $child))+
+    );
+}
+
+impl<'a, Key, Message, Theme, Renderer> Default for Column<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> FromIterator<(Key, Element<'a, Message, Theme, Renderer>)>
+    for Column<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn from_iter<T: IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>>(
+        iter: T,
+    ) -> Self {
+        Self::with_children(iter)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Column<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn state(&self) -> iced::advanced::widget::tree::State {
+        iced::advanced::widget::tree::State::new(State {
+            keys: self.keys.clone(),
+            ..State::default()
+        })
+    }
+
+    fn tag(&self) -> iced::advanced::widget::tree::Tag {
+        iced::advanced::widget::tree::Tag::of::<State<Key>>()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.build_lazy_children();
+        self.children
+            .borrow()
+            .iter()
+            .chain(self.statics.iter().map(|(_, child)| child))
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.build_lazy_children();
+        let previous_keys = tree.state.downcast_ref::<State<Key>>().keys.len();
+        let mut static_trees = tree
+            .children
+            .split_off(previous_keys.min(tree.children.len()));
+        let state = tree.state.downcast_mut::<State<Key>>();
+        if state
+            .drag
+            .key()
+            .is_some_and(|key| !self.keys.contains(&key))
+        {
+            state.drag = DragState::Idle;
+        }
+
+        // Reconcile the child trees by key, so that the state of each child element,
+        // including the dragged one, follows its key when the children are reordered.
+        if state.keys != self.keys {
+            let mut previous: Vec<_> = state
+                .keys
+                .iter()
+                .copied()
+                .zip(tree.children.drain(..).map(Some))
+                .collect();
+            tree.children = self
+                .keys
+                .iter()
+                .enumerate()
+                .map(|(index, key)| {
+                    let matches = |(previous_key, child): &(Key, Option<Tree>)| {
+                        previous_key == key && child.is_some()
+                    };
+                    // Most updates keep the child elements in place, so try the same index first
+                    let child = if previous.get(index).is_some_and(matches) {
+                        previous[index].1.take()
+                    } else {
+                        previous
+                            .iter_mut()
+                            .find(|entry| matches(entry))
+                            .and_then(|(_, child)| child.take())
+                    };
+                    child.unwrap_or_else(Tree::empty)
+                })
+                .collect();
+            state.keys = self.keys.clone();
+            if cfg!(debug_assertions) {
+                state.duplicate = error::find_duplicate(&self.keys);
+            }
+            // The child elements measured so far may have moved
+            state.measured = Vec::new();
+        }
+
+        self.refresh_status_children(&state.drag);
+        tree.diff_children(&self.children.borrow());
+
+        static_trees.truncate(self.statics.len());
+        for (child_tree, (_, child)) in static_trees.iter_mut().zip(&self.statics) {
+            child_tree.diff(child);
+        }
+        static_trees.extend(
+            self.statics[static_trees.len()..]
+                .iter()
+                .map(|(_, child)| Tree::new(child)),
+        );
+        tree.children.extend(static_trees);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.lengths()
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let drag_state = tree.state.downcast_ref::<State<Key>>().drag;
+        if let Some((event, cursor)) =
+            flex::propagate_event_to_children(&drag_state, self.drag_threshold, &event, cursor)
+        {
+            for ((child, state), item_layout) in self
+                .children
+                .get_mut()
                 .iter_mut()
                 .zip(&mut tree.children)
                 .zip(layout.children())
@@ -423,115 +3357,297 @@ where
                     viewport,
                 );
             }
+
+            let offset = layout.position() - Point::ORIGIN;
+            let static_nodes = &tree.state.downcast_ref::<State<Key>>().static_nodes;
+            for (((_, child), state), static_node) in self
+                .statics
+                .iter_mut()
+                .zip(tree.children.iter_mut().skip(self.keys.len()))
+                .zip(static_nodes)
+            {
+                child.as_widget_mut().update(
+                    state,
+                    event,
+                    Layout::with_offset(offset, static_node),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
         }
 
         let state = tree.state.downcast_mut::<State<Key>>();
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if !shell.is_event_captured() && cursor.is_over(layout.bounds()) {
-                    let mut position = cursor.position().unwrap();
-                    for (key, item_layout) in self.keys.iter().zip(layout.children()) {
-                        if cursor.is_over(item_layout.bounds()) {
-                            if let Some(on_grab) = &self.on_grab {
-                                shell.publish(on_grab(*key));
-                            };
-                            if self.drag_center {
-                                let origin = item_layout.bounds().center();
-                                if !self.drag_lateral {
-                                    position.x = origin.x;
-                                }
-                                let drop_location = drop_location(&layout, position);
-                                if let Some(on_drag) = self.on_drag.as_deref() {
-                                    if Some(drop_location) != state.drag.drop_location() {
-                                        let message = (on_drag)(*key, drop_location);
-                                        shell.publish(message);
-                                    }
-                                }
-                                state.drag = DragState::Dragged {
-                                    key: *key,
-                                    origin,
-                                    position,
-                                    drop_location,
-                                };
-                            } else {
-                                let origin = position;
-                                state.drag = DragState::Grabbed { key: *key, origin };
-                            };
-                            shell.request_redraw();
-                            break;
-                        }
+        if let Some((key, origin)) = state.tap {
+            match event {
+                Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if cursor
+                        .position()
+                        .is_some_and(|position| position.distance(origin) > TAP_TOLERANCE)
+                    {
+                        state.tap = None;
                     }
                 }
-            }
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
-            | Event::Touch(touch::Event::FingerLost { .. }) => {
-                if let Some(key) = state.drag.key() {
-                    state.drag = DragState::Idle;
-                    if let Some(on_cancel) = &self.on_cancel {
-                        shell.publish(on_cancel(key));
+                Event::Touch(touch::Event::FingerLifted { .. }) => {
+                    state.tap = None;
+                    let is_tapped =
+                        self.keys
+                            .iter()
+                            .zip(layout.children())
+                            .any(|(item_key, item_layout)| {
+                                *item_key == key && cursor.is_over(item_layout.bounds())
+                            });
+                    if let Some(on_select) = self.on_select.as_deref().filter(|_| is_tapped) {
+                        shell.publish(on_select(key));
+                        shell.capture_event();
                     }
-                    shell.request_redraw();
                 }
+                Event::Touch(touch::Event::FingerLost { .. }) => {
+                    state.tap = None;
+                }
+                _ => {}
+            }
+        }
+
+        let was_idle = state.drag.is_idle();
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+        if is_press {
+            state.pressed_gap = None;
+            state.pressed_button = None;
+            if state.focused.take().is_some() {
+                shell.request_redraw();
+            }
+        }
+
+        let mut gesture = DragGesture {
+            column: &*self,
+            children: &mut tree.children,
+            renderer,
+            viewport,
+            cursor,
+        };
+        flex::update_picked_up(
+            &Self::AXIS,
+            &gesture,
+            state,
+            event,
+            layout,
+            cursor,
+            shell,
+            viewport,
+        );
+        if let Some(button) = cursor
+            .position()
+            .filter(|_| is_press && state.drag.is_idle() && !shell.is_event_captured())
+            .and_then(|position| self.reorder_button_at(&layout, position))
+        {
+            state.pressed_button = Some(button);
+            shell.request_redraw();
+            shell.capture_event();
+        }
+        let pressed = cursor
+            .position_over(layout.bounds())
+            .filter(|_| is_press && !shell.is_event_captured());
+        if pressed.is_some() {
+            state.picked_up = false;
+        }
+        flex::update(&mut gesture, state, event, layout, cursor, shell);
+        if let Some(position) = pressed {
+            if self.on_gap_click.is_some() {
+                state.pressed_gap =
+                    flex::gap_at(&Self::AXIS, &layout, position).map(|gap| (gap, position));
+            }
+            let is_over_child = layout
+                .children()
+                .any(|item_layout| item_layout.bounds().contains(position));
+            if self.rubber_band
+                && self.on_selection_change.is_some()
+                && matches!(event, Event::Mouse(_))
+                && !is_over_child
+            {
+                let base = if state.modifiers.command() {
+                    self.selection.clone()
+                } else {
+                    Vec::new()
+                };
+                state.rubber_band = Some(RubberBand {
+                    origin: position,
+                    position,
+                    base,
+                });
+            }
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. }) => match state.drag {
-                DragState::Grabbed { key, origin: _ } => {
-                    if let Some(on_cancel) = &self.on_cancel {
-                        shell.publish(on_cancel(key));
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if was_idle =>
+            {
+                if state.rubber_band.take().is_some() {
+                    shell.request_redraw();
+                }
+                if let Some((id, key, drop_location)) = state
+                    .incoming
+                    .take()
+                    .filter(|(_, key, drop_location)| self.accepts(*key, *drop_location))
+                {
+                    let is_live = self
+                        .drag_group
+                        .as_ref()
+                        .is_some_and(|group| group.is_live(id));
+                    let payload = self
+                        .drag_group
+                        .as_ref()
+                        .and_then(|group| group.payload_of(id));
+                    if let Some((on_transfer_payload, payload)) =
+                        self.on_transfer_payload.as_deref().zip(payload)
+                    {
+                        shell.publish(on_transfer_payload(payload, drop_location));
+                        shell.capture_event();
+                    } else if let Some(on_transfer) =
+                        self.on_transfer.as_deref().filter(|_| is_live)
+                    {
+                        shell.publish(on_transfer(key, drop_location));
+                        shell.capture_event();
                     }
-                    state.drag = DragState::Idle;
+                    shell.request_redraw();
                 }
-                DragState::Dragged {
-                    key,
-                    origin: _,
-                    position,
-                    drop_location: _,
-                } => {
-                    if let Some(on_drop) = self.on_drop.as_deref() {
-                        let drop_index = drop_location(&layout, position);
-                        let message = (on_drop)(key, drop_index);
-                        shell.publish(message);
+                if let Some((index, slot)) = state.pressed_button.take() {
+                    let clicked = cursor.position().filter(|position| {
+                        self.reorder_button_at(&layout, *position) == Some((index, slot))
+                    });
+                    if let Some((key, position)) = self.keys.get(index).zip(clicked) {
+                        let modifiers = state.modifiers;
+                        self.publish_drop(shell, &layout, *key, slot.0, position, modifiers);
+                        shell.capture_event();
                     }
-                    state.drag = DragState::Idle;
+                    shell.request_redraw();
                 }
-                _ => (),
-            },
+                if let Some((gap, origin)) = state.pressed_gap.take() {
+                    let is_clicked = cursor.position().is_some_and(|position| {
+                        position.distance(origin) <= TAP_TOLERANCE
+                            && flex::gap_at(&Self::AXIS, &layout, position) == Some(gap)
+                    });
+                    if let Some(on_gap_click) = self.on_gap_click.as_deref().filter(|_| is_clicked)
+                    {
+                        shell.publish(on_gap_click(gap));
+                        shell.capture_event();
+                    }
+                }
+            }
             Event::Mouse(mouse::Event::CursorMoved { .. })
-            | Event::Touch(touch::Event::FingerMoved { .. }) => match state.drag {
-                DragState::Grabbed { key, origin } | DragState::Dragged { key, origin, .. } => {
-                    if cursor.position() == state.drag.last_position() {
-                        return;
-                    } else if let Some(mut position) = cursor.position() {
-                        if !self.drag_lateral {
-                            position.x = origin.x;
-                        }
-                        let drop_location = drop_location(&layout, position);
-                        if let Some(on_drag) = self.on_drag.as_deref() {
-                            if Some(drop_location) != state.drag.drop_location() {
-                                let message = (on_drag)(key, drop_location);
-                                shell.publish(message);
-                            }
-                        }
-                        state.drag = DragState::Dragged {
-                            key,
-                            origin,
-                            position,
-                            drop_location,
-                        };
-                        if self.drag_follow {
-                            shell.request_redraw();
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if was_idle =>
+            {
+                if let (Some(band), Some(position)) =
+                    (state.rubber_band.as_mut(), cursor.position())
+                {
+                    band.position = position;
+                    let selection = self.band_selection(&layout, band);
+                    if selection != self.selection {
+                        if let Some(on_selection_change) = &self.on_selection_change {
+                            shell.publish(on_selection_change(selection));
                         }
                     }
+                    shell.request_redraw();
+                    shell.capture_event();
                 }
-                _ => {
-                    if cursor.is_over(layout.bounds()) {
+                self.receive_drag(state, shell, &layout, cursor.position());
+                self.hover(state, shell, &layout, cursor);
+                if let Some(on_gap_hover) = self.on_gap_hover.as_deref() {
+                    let gap = cursor
+                        .position_over(layout.bounds())
+                        .and_then(|position| flex::gap_at(&Self::AXIS, &layout, position));
+                    if gap != state.hovered_gap {
+                        state.hovered_gap = gap;
+                        if let Some(gap) = gap {
+                            shell.publish(on_gap_hover(gap));
+                        }
                         shell.request_redraw();
                     }
                 }
-            },
+                if self.redraw_on_hover && cursor.is_over(layout.bounds()) {
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorLeft) if state.drag.is_idle() => {
+                self.hover(state, shell, &layout, mouse::Cursor::Unavailable);
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if self.advance_motions(state, layout, *now) {
+                    shell.request_redraw();
+                }
+                if self.settle_translation(state, layout, *now).is_some() {
+                    shell.request_redraw();
+                } else {
+                    state.settle = None;
+                }
+                if self.incremental_layout.is_some() && !state.layout_complete {
+                    // Measure the next child elements in the next frame
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+                if let (
+                    Some(position),
+                    DragState::Dragged {
+                        key,
+                        origin,
+                        offset,
+                        ..
+                    },
+                ) = (state.pending_position.take(), state.drag)
+                {
+                    self.drag_to(state, shell, &layout, (key, origin, offset), position);
+                }
+            }
             _ => {}
         }
+        if let Some(on_auto_scroll) = self.on_auto_scroll.as_deref() {
+            flex::auto_scroll(
+                &Self::AXIS,
+                &gesture,
+                state,
+                event,
+                layout,
+                cursor,
+                shell,
+                viewport,
+                on_auto_scroll,
+            );
+        }
+
+        self.report_errors(tree.state.downcast_mut::<State<Key>>(), shell);
+
+        if let Some(group) = &self.drag_group {
+            // Keep the group in sync with drags ending without a drop
+            let state = tree.state.downcast_mut::<State<Key>>();
+            if state.drag.is_idle()
+                && group
+                    .drag()
+                    .is_some_and(|drag| Some(drag.source) == state.member)
+            {
+                group.cancel();
+            }
+            if state.incoming.is_some_and(|(id, _, _)| !group.is_live(id)) {
+                state.incoming = None;
+                shell.request_redraw();
+            }
+        }
+
+        let state = tree.state.downcast_ref::<State<Key>>();
+        if self.refresh_status_children(&state.drag) {
+            tree.diff_children(self.children.get_mut());
+            shell.invalidate_layout();
+        }
     }
 
     fn layout(
@@ -542,18 +3658,43 @@ where
     ) -> layout::Node {
         let limits = limits.max_width(self.max_width);
 
-        layout::flex::resolve(
-            layout::flex::Axis::Vertical,
-            renderer,
-            &limits,
-            self.width,
-            self.height,
-            self.padding,
-            self.spacing,
-            self.align,
-            &self.children,
-            &mut tree.children,
-        )
+        let node = if let Some(children_per_frame) = self
+            .incremental_layout
+            .filter(|_| !tree.state.downcast_ref::<State<Key>>().layout_complete)
+        {
+            let state = tree.state.downcast_mut::<State<Key>>();
+            self.layout_incrementally(
+                state,
+                &mut tree.children,
+                renderer,
+                &limits,
+                children_per_frame,
+            )
+        } else {
+            let node = layout::flex::resolve(
+                Self::AXIS,
+                renderer,
+                &limits,
+                self.lengths().width,
+                self.lengths().height,
+                self.content_padding(),
+                self.spacing,
+                self.align,
+                &self.children.borrow(),
+                &mut tree.children,
+            );
+
+            if self.item_max_height.is_finite() {
+                self.clamp_item_heights(node, tree, renderer, &limits)
+            } else {
+                node
+            }
+        };
+
+        let (node, static_nodes) = self.layout_statics(node, &mut tree.children, renderer, &limits);
+        tree.state.downcast_mut::<State<Key>>().static_nodes = static_nodes;
+
+        node
     }
 
     fn operate(
@@ -563,16 +3704,94 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
-        operation.container(None, layout.bounds(), &mut |operation| {
+        let mut items = Items {
+            column: layout.bounds(),
+            bounds: self
+                .keys
+                .iter()
+                .copied()
+                .zip(layout.children().map(|item_layout| item_layout.bounds()))
+                .collect(),
+            grab: None,
+            focus: None,
+            dragging: matches!(
+                tree.state.downcast_ref::<State<Key>>().drag,
+                DragState::Dragged { .. }
+            ),
+            drag: tree.state.downcast_ref::<State<Key>>().drag.info(),
+        };
+        operation.custom(&mut items, self.id.as_ref());
+        if let Some(key) = items.grab {
+            self.pick_up(tree.state.downcast_mut::<State<Key>>(), &layout, key);
+        }
+        if let Some(key) = items.focus.filter(|key| {
+            self.keys
+                .iter()
+                .zip(&self.placeholders)
+                .any(|(item_key, is_placeholder)| item_key == key && !*is_placeholder)
+                && !self.pinned.contains(key)
+        }) {
+            tree.state.downcast_mut::<State<Key>>().focused = Some(key);
+        }
+
+        let dragged = self.dragged_translation(tree.state.downcast_ref::<State<Key>>(), layout);
+        let column_state = tree.state.downcast_mut::<State<Key>>();
+        let is_focusable = self.has_drop_messages();
+
+        operation.container(self.id.as_ref(), layout.bounds(), &mut |operation| {
             self.children
+                .borrow()
                 .iter()
+                .zip(&self.keys)
+                .zip(&self.placeholders)
                 .zip(&mut tree.children)
                 .zip(layout.children())
-                .for_each(|((child, state), item_layout)| {
-                    child
-                        .as_widget()
-                        .operate(state, item_layout, renderer, operation);
+                .for_each(|((((child, key), is_placeholder), state), item_layout)| {
+                    // Expose the grabbable child elements to the focus operations
+                    if is_focusable && !*is_placeholder && !self.pinned.contains(key) {
+                        operation.focusable(
+                            &mut ItemFocus {
+                                key: *key,
+                                focused: &mut column_state.focused,
+                            },
+                            None,
+                        );
+                    }
+                    match dragged {
+                        Some((dragged_key, translation)) if dragged_key == *key => {
+                            child.as_widget().operate(
+                                state,
+                                item_layout,
+                                renderer,
+                                &mut Translate {
+                                    operation,
+                                    translation,
+                                },
+                            );
+                        }
+                        _ => {
+                            child
+                                .as_widget()
+                                .operate(state, item_layout, renderer, operation);
+                        }
+                    }
                 });
+
+            let offset = layout.position() - Point::ORIGIN;
+            let static_nodes = &column_state.static_nodes;
+            for (((_, child), state), static_node) in self
+                .statics
+                .iter()
+                .zip(tree.children.iter_mut().skip(self.keys.len()))
+                .zip(static_nodes)
+            {
+                child.as_widget().operate(
+                    state,
+                    Layout::with_offset(offset, static_node),
+                    renderer,
+                    operation,
+                );
+            }
         });
     }
 
@@ -584,12 +3803,20 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        let drag_state = tree.state.downcast_ref::<State<Key>>().drag;
-        if !drag_state.is_idle() {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        if !state.drag.is_idle() || state.incoming.is_some() {
             return mouse::Interaction::Grabbing;
         }
+        if cursor
+            .position()
+            .and_then(|position| self.reorder_button_at(&layout, position))
+            .is_some()
+        {
+            return mouse::Interaction::Pointer;
+        }
 
         self.children
+            .borrow()
             .iter()
             .zip(&tree.children)
             .zip(layout.children())
@@ -607,149 +3834,909 @@ where
                     child_interaction
                 }
             })
+            .chain(
+                self.static_layouts(&tree.children, &state.static_nodes, layout)
+                    .map(|(child, child_tree, static_layout)| {
+                        child.as_widget().mouse_interaction(
+                            child_tree,
+                            static_layout,
+                            cursor,
+                            viewport,
+                            renderer,
+                        )
+                    }),
+            )
             .max()
             .unwrap_or_default()
     }
 
-    fn draw(
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(clipped_viewport) = layout.bounds().intersection(viewport) {
+            let viewport = if self.clip {
+                &clipped_viewport
+            } else {
+                viewport
+            };
+            let state = tree.state.downcast_ref::<State<Key>>();
+            let status = self.status(state);
+            // A dropped child element settles into its slot without the lift
+            let is_lifted = !state.drag.is_idle();
+
+            let mut deferred_drop_marker = None;
+            let (deferred_dragged_elem_key, deferred_dragged_elem_translation) =
+                match self.floating_translation(state, layout) {
+                    Some((key, translation)) => (Some(key), translation),
+                    None => (None, Vector::ZERO),
+                };
+            // Nothing is left behind by a dropped child element settling into its slot
+            let origin_placeholder = if state.drag.is_idle() {
+                OriginPlaceholder::Hide
+            } else {
+                self.origin_placeholder
+            };
+
+            let displacement = self.displacement(state, layout);
+            let motion_translations = self.motion_translations(state, layout);
+
+            if self.drop_position_marker {
+                let color = theme.style(&self.class, status).color;
+                match state.drag {
+                    DragState::Dragged {
+                        key,
+                        drop_location,
+                        position,
+                        ..
+                    } if matches!(
+                        self.presentation,
+                        Presentation::Marker | Presentation::GapHighlight
+                    ) && self.on_drop_position.is_none()
+                        && self.accepts(key, drop_location)
+                        && (!self.can_leave() || layout.bounds().contains(position)) =>
+                    {
+                        deferred_drop_marker = self
+                            .marker_bounds(&layout, drop_location)
+                            .map(|bounds| (bounds, color));
+                    }
+                    DragState::Idle if state.incoming.is_some() => {
+                        deferred_drop_marker = state
+                            .incoming
+                            .filter(|(_, key, drop_location)| self.accepts(*key, *drop_location))
+                            .and_then(|(_, _, drop_location)| {
+                                self.marker_bounds(&layout, drop_location)
+                            })
+                            .map(|bounds| (bounds, color));
+                    }
+                    DragState::Idle if self.on_gap_hover.is_some() => {
+                        deferred_drop_marker = state
+                            .hovered_gap
+                            .and_then(|gap| self.marker_bounds(&layout, gap))
+                            .map(|bounds| (bounds, color.scale_alpha(0.3)));
+                    }
+                    _ => {}
+                }
+            }
+
+            // Highlight the child element dropped onto or swapped with, or collapsed section
+            // headers instead of marking the drop location
+            let mut deferred_target_highlight = None;
+            if deferred_drop_marker.is_some() {
+                let drop_location = match state.drag {
+                    DragState::Dragged { drop_location, .. } => Some(drop_location),
+                    _ => state.incoming.map(|(_, _, drop_location)| drop_location),
+                };
+                let drop_target = match state.drag {
+                    DragState::Dragged { key, position, .. } => {
+                        self.drop_target(&layout, key, position)
+                    }
+                    _ => None,
+                };
+                if let Some(target) = drop_target {
+                    deferred_drop_marker = None;
+                    deferred_target_highlight = layout
+                        .children()
+                        .nth(target)
+                        .map(|target_layout| target_layout.bounds());
+                } else if self.drop_mode == DropMode::Swap {
+                    deferred_drop_marker = None;
+                    deferred_target_highlight = drop_location
+                        .filter(|target| self.keys.get(*target).copied() != state.drag.key())
+                        .and_then(|target| layout.children().nth(target))
+                        .map(|target_layout| target_layout.bounds());
+                } else if let Some((header, _)) =
+                    drop_location.and_then(|drop_location| self.collapsed_header(drop_location))
+                {
+                    deferred_drop_marker = None;
+                    deferred_target_highlight = layout
+                        .children()
+                        .nth(header)
+                        .map(|header_layout| header_layout.bounds());
+                }
+            }
+
+            let mut deferred_dragged_elem = None;
+            let children = self.children.borrow();
+            let focused_layout = state
+                .focused
+                .filter(|key| state.drag.key() != Some(*key))
+                .and_then(|key| self.keys.iter().position(|item_key| *item_key == key))
+                .and_then(|index| layout.children().nth(index));
+
+            for (index, (((child, key), state), item_layout)) in children
+                .iter()
+                .zip(&self.keys)
+                .zip(&tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                if Some(*key) == deferred_dragged_elem_key {
+                    deferred_dragged_elem = Some((child, state, item_layout));
+                    match origin_placeholder {
+                        OriginPlaceholder::Hide => {}
+                        OriginPlaceholder::Ghost => {
+                            let color = theme.style(&self.class, status).color;
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: item_layout.bounds(),
+                                    border: Border {
+                                        color: color.scale_alpha(0.4),
+                                        width: 1.0,
+                                        radius: 2.0.into(),
+                                    },
+                                    ..renderer::Quad::default()
+                                },
+                                color.scale_alpha(0.1),
+                            );
+                        }
+                        OriginPlaceholder::Keep => child.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            style,
+                            item_layout,
+                            cursor,
+                            viewport,
+                        ),
+                    }
+                    continue;
+                }
+
+                let translation = match (&motion_translations, displacement) {
+                    (Some(translations), _) => translations.get(index).copied(),
+                    (None, Some(displacement)) => Some(self.displaced(layout, index, displacement)),
+                    (None, None) => None,
+                };
+                if let Some(translation) = translation
+                    .filter(|translation| displacement.is_some() || *translation != Vector::ZERO)
+                {
+                    let is_dragged = displacement.is_some_and(|(from, _, _)| index == from);
+                    if (is_dragged && self.presentation == Presentation::Gap)
+                        || !(item_layout.bounds() + translation).intersects(viewport)
+                    {
+                        continue;
+                    }
+                    renderer.with_translation(translation, |renderer| {
+                        child.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            style,
+                            item_layout,
+                            cursor,
+                            viewport,
+                        );
+                    });
+                    continue;
+                }
+
+                if !item_layout.bounds().intersects(viewport) {
+                    continue;
+                }
+
+                if item_layout.bounds().height >= self.item_max_height {
+                    if let Some(item_viewport) = item_layout.bounds().intersection(viewport) {
+                        renderer.with_layer(item_viewport, |renderer| {
+                            child.as_widget().draw(
+                                state,
+                                renderer,
+                                theme,
+                                style,
+                                item_layout,
+                                cursor,
+                                &item_viewport,
+                            );
+                        });
+                    }
+                    continue;
+                }
+
+                child.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    item_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+
+            if let Some(item_layout) = focused_layout {
+                let color = theme.style(&self.class, status).color;
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: item_layout.bounds(),
+                        border: Border {
+                            color,
+                            width: flex::MARKER_LINE_WIDTH,
+                            radius: 2.0.into(),
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            for (child, child_tree, static_layout) in
+                self.static_layouts(&tree.children, &state.static_nodes, layout)
+            {
+                if static_layout.bounds().intersects(viewport) {
+                    child.as_widget().draw(
+                        child_tree,
+                        renderer,
+                        theme,
+                        style,
+                        static_layout,
+                        cursor,
+                        viewport,
+                    );
+                }
+            }
+
+            if self.incremental_layout.is_some() && !state.layout_complete {
+                self.draw_pending_rows(renderer, theme, status, layout, viewport);
+            }
+
+            if self.has_reorder_buttons() {
+                self.draw_reorder_buttons(state, renderer, theme, layout, cursor, viewport);
+            }
+
+            if let Some(band) = &state.rubber_band {
+                let color = theme.style(&self.class, status).color;
+                renderer.with_layer(*viewport, |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: band.bounds(),
+                            border: Border {
+                                color,
+                                width: 1.0,
+                                radius: 0.0.into(),
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        color.scale_alpha(0.15),
+                    );
+                });
+            }
+
+            if deferred_drop_marker.is_some()
+                || deferred_target_highlight.is_some()
+                || deferred_dragged_elem.is_some()
+            {
+                renderer.with_layer(*viewport, |renderer| {
+                    if let (Some((marker_bounds, _)), Some(draw_marker)) =
+                        (deferred_drop_marker, self.draw_marker.as_deref())
+                    {
+                        draw_marker(renderer, marker_bounds, theme);
+                    } else if let Some((marker_bounds, color)) = deferred_drop_marker
+                        .filter(|_| self.presentation == Presentation::GapHighlight)
+                    {
+                        let bounds = layout.bounds();
+                        let height = self.spacing.max(flex::MARKER_LINE_WIDTH);
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    y: marker_bounds.center_y() - height * 0.5,
+                                    height,
+                                    ..bounds
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            color.scale_alpha(0.3),
+                        );
+                    } else if let Some((marker_bounds, color)) = deferred_drop_marker {
+                        let marker_style = Style {
+                            color,
+                            ..theme.style(&self.class, status)
+                        };
+                        flex::draw_marker(
+                            renderer,
+                            &Self::AXIS,
+                            marker_bounds,
+                            viewport,
+                            marker_style,
+                        );
+                    }
+                    if let Some(target_bounds) = deferred_target_highlight {
+                        let target = theme.style(&self.class, status).target;
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: target_bounds,
+                                border: Border {
+                                    color: target.border_color,
+                                    width: target.border_width,
+                                    radius: target.border_radius.into(),
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            target.background,
+                        );
+                    }
+                    if let Some((child, state, layout)) = deferred_dragged_elem {
+                        let bounds = layout.bounds();
+                        let center = bounds.center();
+                        let (scale, opacity) = if is_lifted {
+                            (self.drag_scale, self.drag_opacity)
+                        } else {
+                            (1.0, 1.0)
+                        };
+                        let transformation = Transformation::translate(center.x, center.y)
+                            * Transformation::scale(scale)
+                            * Transformation::translate(-center.x, -center.y);
+                        let style = renderer::Style {
+                            text_color: style.text_color.scale_alpha(opacity),
+                        };
+                        renderer.with_translation(deferred_dragged_elem_translation, |renderer| {
+                            renderer.with_transformation(transformation, |renderer| {
+                                if let Some(shadow) = self.drag_shadow.filter(|_| is_lifted) {
+                                    renderer.fill_quad(
+                                        renderer::Quad {
+                                            bounds,
+                                            shadow,
+                                            ..renderer::Quad::default()
+                                        },
+                                        Color::TRANSPARENT,
+                                    );
+                                }
+                                child
+                                    .as_widget()
+                                    .draw(state, renderer, theme, &style, layout, cursor, viewport);
+                            });
+                        });
+                    }
+                });
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let dragged_key = state.drag.key();
+        let (follow_key, follow_translation) = match self.floating_translation(state, layout) {
+            Some((key, translation)) => (Some(key), translation),
+            None => (None, Vector::ZERO),
+        };
+
+        let children = self
+            .children
+            .get_mut()
+            .iter_mut()
+            .zip(&self.keys)
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter(|(((_, key), _), _)| {
+                !self.hide_sibling_overlays || dragged_key.is_none() || dragged_key == Some(**key)
+            })
+            .filter_map(|(((child, key), state), item_layout)| {
+                let translation = if Some(*key) == follow_key {
+                    translation + follow_translation
+                } else {
+                    translation
+                };
+                child
+                    .as_widget_mut()
+                    .overlay(state, item_layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+/// The drag gesture of a [`Column`], adapting the steps of the flex core to its filters,
+/// selection, clicks and drag options.
+struct DragGesture<'c, 'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    column: &'c Column<'a, Key, Message, Theme, Renderer>,
+    children: &'c mut [Tree],
+    renderer: &'c Renderer,
+    viewport: &'c Rectangle,
+    cursor: mouse::Cursor,
+}
+
+impl<'c, 'a, Key, Message, Theme, Renderer> flex::Gesture<Key, Message>
+    for DragGesture<'c, 'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    type State = State<Key>;
+
+    fn drag<'s>(&self, state: &'s mut State<Key>) -> &'s mut DragState<Key> {
+        &mut state.drag
+    }
+
+    fn grab_at(
+        &mut self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        event: &Event,
+        position: Point,
+    ) -> Option<(Key, Rectangle)> {
+        let column = self.column;
+        let (index, (key, item_layout)) = column
+            .keys
+            .iter()
+            .copied()
+            .zip(layout.children())
+            .enumerate()
+            .find(|(_, (_, item_layout))| item_layout.bounds().contains(position))?;
+        if column.is_placeholder(index) || column.pinned.contains(&key) {
+            return None;
+        }
+        let handles =
+            column.drag_handles(index, &mut self.children[index], item_layout, self.renderer);
+        if !handles.is_empty() && !handles.iter().any(|handle| handle.contains(position)) {
+            return None;
+        }
+        if column.grab_policy == GrabPolicy::NonInteractive
+            && column.is_interactive(
+                index,
+                &self.children[index],
+                item_layout,
+                self.cursor,
+                self.viewport,
+                self.renderer,
+            )
+        {
+            return None;
+        }
+        if let Some(on_selection_change) = column
+            .on_selection_change
+            .as_deref()
+            .filter(|_| matches!(event, Event::Mouse(_)))
+        {
+            let (selection, may_drag) = column.select(state, key);
+            if let Some(selection) = selection {
+                shell.publish(on_selection_change(selection));
+            }
+            if !may_drag {
+                shell.capture_event();
+                return None;
+            }
+        }
+        if column.on_select.is_some()
+            && matches!(event, Event::Touch(_))
+            && column.selected != Some(key)
+        {
+            state.tap = Some((key, position));
+            return None;
+        }
+
+        Some((key, item_layout.bounds()))
+    }
+
+    fn drop_location(&self, layout: Layout<'_>, key: Key, position: Point) -> usize {
+        self.column.drop_location(&layout, key, position)
+    }
+
+    fn cancel_message(&self, key: Key) -> Option<Message> {
+        self.column
+            .on_cancel
+            .as_ref()
+            .map(|on_cancel| on_cancel(key))
+    }
+
+    fn drag_threshold(&self) -> f32 {
+        self.column.drag_threshold
+    }
+
+    fn hold(&self) -> Option<flex::Hold> {
+        self.column.clock.hold(self.column.mouse_hold_delay)
+    }
+
+    fn constrain(&self, position: Point, origin: Point) -> Point {
+        self.column.constrain(position, origin)
+    }
+
+    fn grab(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        event: &Event,
+        (key, bounds): (Key, Rectangle),
+        position: Point,
+    ) {
+        let column = self.column;
+        if let Some(on_grab) = &column.on_grab {
+            shell.publish(on_grab(key));
+        }
+        if column.drag_center {
+            let origin = bounds.center();
+            let position = if column.drag_lateral {
+                position
+            } else {
+                flex::lock_cross(&Axis::Vertical, position, origin)
+            };
+            let offset = origin - bounds.position();
+            column.drag_to(state, shell, &layout, (key, origin, offset), position);
+        } else {
+            state.drag = flex::grabbed(event, (key, bounds), position, self.hold());
+        }
+        column.share_drag(state, key);
+    }
+
+    fn drag_to(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        position: Point,
+    ) {
+        if let DragState::Grabbed {
+            key,
+            origin,
+            offset,
+            ..
+        }
+        | DragState::Dragged {
+            key,
+            origin,
+            offset,
+            ..
+        } = state.drag
+        {
+            self.column
+                .drag_to(state, shell, &layout, (key, origin, offset), position);
+        }
+    }
+
+    fn drag_move(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        position: Point,
+    ) {
+        let (DragState::Grabbed {
+            key,
+            origin,
+            offset,
+            ..
+        }
+        | DragState::Dragged {
+            key,
+            origin,
+            offset,
+            ..
+        }) = state.drag
+        else {
+            return;
+        };
+        let column = self.column;
+        if let Some(on_drag_move) = &column.on_drag_move {
+            shell.publish(on_drag_move(key, position));
+        }
+        let position = column.constrain(position, origin);
+        if column.frame_sync && matches!(state.drag, DragState::Dragged { .. }) {
+            // Coalesce the moves until the next frame
+            if state.pending_position.replace(position).is_none() {
+                shell.request_redraw();
+            }
+        } else {
+            column.drag_to(state, shell, &layout, (key, origin, offset), position);
+            if column.drag_follow {
+                shell.request_redraw();
+            }
+        }
+    }
+
+    fn drop(
         &self,
-        tree: &Tree,
-        renderer: &mut Renderer,
-        theme: &Theme,
-        style: &renderer::Style,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
         layout: Layout<'_>,
-        cursor: mouse::Cursor,
-        viewport: &Rectangle,
+        position: Point,
     ) {
-        if let Some(clipped_viewport) = layout.bounds().intersection(viewport) {
-            let viewport = if self.clip {
-                &clipped_viewport
-            } else {
-                viewport
-            };
-            let state = tree.state.downcast_ref::<State<Key>>();
+        if let DragState::Dragged {
+            key, drop_location, ..
+        } = state.drag
+        {
+            state.pending_position = None;
+            self.column
+                .drop_at(state, shell, &layout, (key, drop_location), position);
+        }
+    }
 
-            let mut deferred_drop_marker_y = None;
-            let mut deferred_dragged_elem_key = None;
-            let mut deferred_dragged_elem_translation = Vector::ZERO;
+    fn release_drag(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let DragState::Dragged {
+            key,
+            origin,
+            position,
+            drop_location,
+            ..
+        } = state.drag
+        else {
+            return;
+        };
+        // A child element picked up with a click is dropped with the next press
+        if state.picked_up {
+            return;
+        }
+        if cursor
+            .position()
+            .is_some_and(|position| self.column.is_click(position, origin))
+        {
+            state.picked_up = true;
+            shell.capture_event();
+        } else {
+            let position = state.pending_position.take().unwrap_or(position);
+            self.column
+                .drop_at(state, shell, &layout, (key, drop_location), position);
+        }
+    }
 
-            if let DragState::Dragged {
-                key,
-                origin,
-                position,
-                drop_location,
-            } = state.drag
-            {
-                if self.drop_position_marker {
-                    deferred_drop_marker_y =
-                        drop_location_marker_y(&layout, self.spacing, drop_location);
-                }
-                if self.drag_follow {
-                    deferred_dragged_elem_key = Some(key);
-                    deferred_dragged_elem_translation = position - origin;
+    fn release_grab(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let DragState::Grabbed {
+            key,
+            origin,
+            offset,
+            ..
+        } = state.drag
+        else {
+            return;
+        };
+        let column = self.column;
+        if let Some(position) = cursor
+            .position()
+            .filter(|position| column.is_click(*position, origin))
+            .filter(|_| !shell.is_event_captured())
+        {
+            state.picked_up = true;
+            let position = column.constrain(position, origin);
+            column.drag_to(state, shell, &layout, (key, origin, offset), position);
+            shell.request_redraw();
+            shell.capture_event();
+        } else {
+            if !shell.is_event_captured() {
+                if let Some(on_cancel) = &column.on_cancel {
+                    shell.publish(on_cancel(key));
                 }
+                column.click(state, shell, &layout, key, cursor);
+                shell.capture_event();
             }
+            state.drag = DragState::Idle;
+        }
+    }
 
-            let mut deferred_dragged_elem = None;
+    fn cancelled(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        _key: Key,
+        drop_location: Option<usize>,
+    ) {
+        self.column
+            .publish_marker(shell, &layout, drop_location, None);
+        if let Some(group) = &self.column.drag_group {
+            group.cancel();
+        }
+        state.picked_up = false;
+        state.pending_position = None;
+    }
+}
 
-            for (((child, key), state), item_layout) in self
-                .children
-                .iter()
-                .zip(&self.keys)
-                .zip(&tree.children)
-                .zip(layout.children())
-                .filter(|(_, item_layout)| item_layout.bounds().intersects(viewport))
-            {
-                if Some(*key) == deferred_dragged_elem_key {
-                    deferred_dragged_elem = Some((child, state, item_layout));
-                    continue;
-                }
+impl<'c, 'a, Key, Message, Theme, Renderer> flex::PickUp<Key, Message>
+    for DragGesture<'c, 'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn picked_up<'s>(&self, state: &'s mut State<Key>) -> &'s mut bool {
+        &mut state.picked_up
+    }
 
-                child.as_widget().draw(
-                    state,
-                    renderer,
-                    theme,
-                    style,
-                    item_layout,
-                    cursor,
-                    viewport,
-                );
-            }
+    fn focused(&self, state: &State<Key>) -> Option<Key> {
+        state.focused
+    }
 
-            if deferred_drop_marker_y.is_some() || deferred_dragged_elem.is_some() {
-                renderer.with_layer(*viewport, |renderer| {
-                    if let Some(line_y) = deferred_drop_marker_y {
-                        let line_color = theme.style(&self.class).color;
-                        let line_width = 2.0;
-                        let circle_outer_radius = 4.0;
-                        let circle_inner_radius = circle_outer_radius - line_width;
-
-                        // Draw line
-                        let marker_line_bounds = Rectangle {
-                            x: layout.bounds().x + self.padding.left + circle_inner_radius,
-                            y: line_y - line_width * 0.5,
-                            width: layout.bounds().width
-                                - self.padding.horizontal()
-                                - circle_inner_radius,
-                            height: line_width,
-                        };
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: marker_line_bounds,
-                                ..renderer::Quad::default()
-                            },
-                            line_color,
-                        );
+    fn len(&self) -> usize {
+        self.column.keys.len()
+    }
 
-                        // Draw circle at the start of the line
-                        let marker_circle_bounds = Rectangle {
-                            x: layout.bounds().x + self.padding.left - circle_outer_radius,
-                            y: line_y - circle_outer_radius,
-                            width: circle_outer_radius * 2.0,
-                            height: circle_outer_radius * 2.0,
-                        };
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: marker_circle_bounds,
-                                border: Border {
-                                    radius: Radius::new(circle_outer_radius),
-                                    color: line_color,
-                                    width: line_width,
-                                },
-                                ..renderer::Quad::default()
-                            },
-                            Color::TRANSPARENT,
-                        );
-                    }
-                    if let Some((child, state, layout)) = deferred_dragged_elem {
-                        renderer.with_translation(deferred_dragged_elem_translation, |renderer| {
-                            child
-                                .as_widget()
-                                .draw(state, renderer, theme, style, layout, cursor, viewport);
-                        });
-                    }
-                });
+    fn keyboard_wrap(&self) -> bool {
+        self.column.keyboard_wrap
+    }
+
+    fn pick_up(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        key: Key,
+    ) -> bool {
+        self.column.pick_up(state, &layout, key);
+        if state.picked_up {
+            if let Some(on_grab) = &self.column.on_grab {
+                shell.publish(on_grab(key));
             }
         }
+
+        state.picked_up
     }
 
-    fn overlay<'b>(
-        &'b mut self,
-        tree: &'b mut Tree,
+    fn reveal(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
         layout: Layout<'_>,
-        renderer: &Renderer,
-        translation: Vector,
-    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+        viewport: &Rectangle,
+    ) {
+        self.column
+            .reveal_drop_location(state, shell, &layout, viewport);
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-struct State<K>
+#[derive(Clone, Debug)]
+pub(crate) struct State<K>
 where
     K: Copy + PartialEq,
 {
     drag: DragState<K>,
+    /// The keys of the child elements the child trees were last reconciled with.
+    keys: Vec<K>,
+    /// The key of the touched child element and the touch position, if the touch may
+    /// become a tap selecting the child element.
+    tap: Option<(K, Point)>,
+    /// The index of the gap between the child elements hovered while not dragging.
+    hovered_gap: Option<usize>,
+    /// The key of the child element hovered while not dragging.
+    hovered: Option<K>,
+    /// Whether the dragged child element is outside of the bounds of the [`Column`].
+    outside: bool,
+    /// The key of the child element clicked last and the time of the click, if the next
+    /// click may complete a [`Column::on_double_click`].
+    last_click: Option<(K, Instant)>,
+    /// The index of the gap between the child elements pressed while not dragging and the
+    /// press position, if the press may become a click.
+    pressed_gap: Option<(usize, Point)>,
+    /// The index of the child element whose reorder button is pressed and the slot the
+    /// button moves it to, if any.
+    pressed_button: Option<(usize, SlotIndex)>,
+    /// The latest position of the dragged child element not processed yet, if
+    /// [`Column::frame_sync`] is enabled.
+    pending_position: Option<Point>,
+    /// Whether the dragged child element was picked up with a click and is dropped with
+    /// the next press, if [`Column::click_to_drag`] is enabled.
+    picked_up: bool,
+    /// The number identifying the [`Column`] in its [`DragGroup`], once it has joined.
+    member: Option<usize>,
+    /// The number of the drag of a child element from another column of the
+    /// [`DragGroup`] hovering the [`Column`], with its key and drop location.
+    incoming: Option<(u64, K, usize)>,
+    /// The indices of the first two child elements sharing a key, found in debug builds
+    /// when the keys change.
+    duplicate: Option<(usize, usize)>,
+    /// The errors already reported with [`Column::on_error`].
+    reported: Vec<ReorderError>,
+    /// The layouts of the child elements measured so far by the initial
+    /// [`Column::incremental_layout`].
+    measured: Vec<layout::Node>,
+    /// The layouts of the [`Column::push_static`] elements, relative to the [`Column`].
+    static_nodes: Vec<layout::Node>,
+    /// Whether the initial [`Column::incremental_layout`] has measured all the child
+    /// elements.
+    layout_complete: bool,
+    /// The current keyboard modifiers, for the clicks changing the [`Column::selection`]
+    /// and the drops with [`Column::on_copy_drop`].
+    modifiers: keyboard::Modifiers,
+    /// The key of the child element last clicked to change the [`Column::selection`],
+    /// where ranges selected with the Shift modifier start.
+    selection_anchor: Option<K>,
+    /// The selection rectangle being drawn with [`Column::rubber_band_selection`].
+    rubber_band: Option<RubberBand<K>>,
+    /// The motions of the child elements animated by the [`Column::animator`], in order.
+    motions: Vec<Motion<K>>,
+    /// The dropped child element settling from its release position into its slot.
+    settle: Option<Settle<K>>,
+    /// The key of the child element focused by a focus operation, e.g. with Tab.
+    focused: Option<K>,
+}
+
+/// The settling of a dropped child element of a [`Column`] into its slot.
+#[derive(Debug, Clone, Copy)]
+struct Settle<K> {
+    key: K,
+    /// The position it was released at, relative to the top-left corner of the [`Column`].
+    from: Vector,
+    start: Instant,
+}
+
+/// The motion of a child element of a [`Column`] towards its target position, relative to
+/// the top-left corner of the [`Column`].
+#[derive(Debug, Clone, Copy)]
+struct Motion<K> {
+    key: K,
+    from: Vector,
+    target: Vector,
+    start: Instant,
+}
+
+impl<K> Motion<K> {
+    /// Returns the position of the child element along the motion at `now`.
+    fn position(&self, animator: &dyn DragAnimator, now: Instant) -> Vector {
+        self.from + (self.target - self.from) * animator.progress(self.start, now)
+    }
+}
+
+/// A selection rectangle drawn over the children of a [`Column`].
+#[derive(Debug, Clone)]
+struct RubberBand<K> {
+    /// The position where the rectangle was started.
+    origin: Point,
+    /// The current position of its opposite corner.
+    position: Point,
+    /// The selection the intersected children are added to.
+    base: Vec<K>,
+}
+
+impl<K> RubberBand<K> {
+    fn bounds(&self) -> Rectangle {
+        Rectangle {
+            x: self.origin.x.min(self.position.x),
+            y: self.origin.y.min(self.position.y),
+            width: (self.origin.x - self.position.x).abs(),
+            height: (self.origin.y - self.position.y).abs(),
+        }
+    }
 }
 
 impl<Key> Default for State<Key>
@@ -759,61 +4746,147 @@ where
     fn default() -> Self {
         Self {
             drag: DragState::Idle,
+            keys: Vec::new(),
+            tap: None,
+            hovered_gap: None,
+            hovered: None,
+            outside: false,
+            last_click: None,
+            pressed_gap: None,
+            pressed_button: None,
+            pending_position: None,
+            picked_up: false,
+            member: None,
+            incoming: None,
+            duplicate: None,
+            reported: Vec::new(),
+            measured: Vec::new(),
+            static_nodes: Vec::new(),
+            layout_complete: false,
+            modifiers: keyboard::Modifiers::default(),
+            selection_anchor: None,
+            rubber_band: None,
+            motions: Vec::new(),
+            settle: None,
+            focused: None,
         }
     }
 }
 
-/// The current dragging state of a [`Column`].
-#[derive(Default, Clone, Copy, PartialEq, Debug)]
-enum DragState<K>
-where
-    K: Copy + PartialEq,
-{
-    /// No child element is being dragged.
+/// The layout of the keyed children of a [`Column`], exposed to operations.
+pub(crate) struct Items<Key> {
+    /// The bounds of the [`Column`].
+    pub(crate) column: Rectangle,
+    /// The keys of the child elements with their bounds.
+    pub(crate) bounds: Vec<(Key, Rectangle)>,
+    /// The key of the child element to pick up, requested by an [`Operation`].
+    pub(crate) grab: Option<Key>,
+    /// The key of the child element to focus, set by [`operation::focus`].
+    pub(crate) focus: Option<Key>,
+    /// Whether one of the child elements is being dragged.
+    pub(crate) dragging: bool,
+    /// The drag in progress, reported by [`operation::drag_state`].
+    pub(crate) drag: Option<operation::DragInfo<Key>>,
+}
+
+/// The drag status of a child element of a [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ItemStatus {
+    /// The child element is not being dragged.
     #[default]
     Idle,
-    /// A [`Column`] child element is grabbed for dragging,
-    /// but has not been moved yet.
-    Grabbed { key: K, origin: Point },
-    /// A [`Column`] child element is being dragged.
-    Dragged {
-        key: K,
-        origin: Point,
-        position: Point,
-        drop_location: usize,
-    },
+    /// The child element is grabbed for dragging, but has not been moved yet.
+    Grabbed,
+    /// The child element is being dragged.
+    Dragged,
 }
 
-impl<K> DragState<K>
-where
-    K: Copy + PartialEq,
-{
-    fn key(&self) -> Option<K> {
-        match self {
-            Self::Idle => None,
-            Self::Grabbed { key, .. } => Some(*key),
-            Self::Dragged { key, .. } => Some(*key),
-        }
-    }
+/// How a [`Column`] presents the drop location of the dragged child element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Presentation {
+    /// A marker line is shown at the drop location.
+    #[default]
+    Marker,
+    /// The spacing between the child elements at the drop location is filled with a
+    /// highlight across the whole [`Column`], which reads better than a line with large
+    /// [`Column::spacing`] values.
+    GapHighlight,
+    /// The siblings make room for the dragged child element, which is shown at the drop
+    /// location, previewing the result of the drop.
+    ///
+    /// The preview is purely presentational: the siblings are only translated while
+    /// drawing, and the new order is still only committed by the application on
+    /// [`Column::on_drop`]. With [`Column::drag_follow`] enabled, the dragged child element
+    /// follows the cursor instead, above the gap left at the drop location.
+    Displace,
+    /// The siblings make room for the dragged child element, leaving an empty gap at the
+    /// drop location.
+    Gap,
+}
 
-    fn is_idle(&self) -> bool {
-        matches!(self, Self::Idle)
-    }
+/// What a [`Column`] leaves at the original position of the child element following the
+/// cursor with [`Column::drag_follow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OriginPlaceholder {
+    /// An empty gap is left.
+    #[default]
+    Hide,
+    /// A translucent ghost of the bounds of the child element is shown.
+    Ghost,
+    /// The child element is still drawn at its original position, under the floating copy.
+    Keep,
+}
 
-    fn last_position(&self) -> Option<Point> {
-        match self {
-            Self::Idle => None,
-            Self::Grabbed { origin, .. } => Some(*origin),
-            Self::Dragged { position, .. } => Some(*position),
-        }
-    }
+/// Which presses on the child elements of a [`Column`] grab them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GrabPolicy {
+    /// Any press that was not captured by the child element grabs it.
+    #[default]
+    Uncaptured,
+    /// Presses over interactive widgets, i.e. widgets showing a mouse interaction like
+    /// a pointer or a text cursor, never grab the child element, even if not captured.
+    NonInteractive,
+}
 
-    fn drop_location(&self) -> Option<usize> {
-        match self {
-            Self::Dragged { drop_location, .. } => Some(*drop_location),
-            _ => None,
-        }
-    }
+/// What dropping the dragged child element on a [`Column`] does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DropMode {
+    /// The child element is inserted at the drop location, between its siblings.
+    #[default]
+    Insert,
+    /// The child element swaps its position with the child element it is dropped onto,
+    /// e.g. in grids of settings or hotbars.
+    Swap,
+}
+
+/// What happens when the dragged child element leaves the bounds of a [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DragExit {
+    /// The drag goes on, and the child element is dropped at the nearest drop location.
+    #[default]
+    Keep,
+    /// The drag is canceled.
+    Cancel,
+}
+
+/// The view function of a [`Column`] created with [`Column::lazy`], along with the sizing
+/// strategy enclosing the child elements it has built.
+struct LazyView<'a, Key, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    view: Box<dyn Fn(Key) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    size: Cell<Size<Length>>,
+}
+
+/// The view function of a [`Column`] created with [`Column::with_children_status`],
+/// along with the [`ItemStatus`] each child element was last built with.
+struct StatusView<'a, Key, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    view: Box<dyn Fn(Key, ItemStatus) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    statuses: RefCell<Vec<ItemStatus>>,
 }
 
 impl<'a, Key, Message, Theme, Renderer> From<Column<'a, Key, Message, Theme, Renderer>>
@@ -829,59 +4902,334 @@ where
     }
 }
 
-/// Returns whether to propagate an [`Event`] to children of a [`Column`].
-///
-/// Will return `false` for mouse and touch events if a child element is being dragged.
-fn propagage_event_to_children<'a, Key>(
-    drag_state: &DragState<Key>,
-    event: &'a Event,
-    cursor: mouse::Cursor,
-) -> Option<(&'a Event, mouse::Cursor)>
+/// The focus of a child element of a [`Column`], exposed to focus operations.
+pub(crate) struct ItemFocus<'a, Key> {
+    pub(crate) key: Key,
+    pub(crate) focused: &'a mut Option<Key>,
+}
+
+impl<Key> Focusable for ItemFocus<'_, Key>
 where
     Key: Copy + PartialEq,
 {
-    if !drag_state.is_idle() {
-        match event {
-            Event::Touch(touch::Event::FingerMoved { .. })
-            | Event::Mouse(mouse::Event::CursorMoved { .. }) => None,
-            _ => Some((event, mouse::Cursor::Unavailable)),
+    fn is_focused(&self) -> bool {
+        *self.focused == Some(self.key)
+    }
+
+    fn focus(&mut self) {
+        *self.focused = Some(self.key);
+    }
+
+    fn unfocus(&mut self) {
+        if self.is_focused() {
+            *self.focused = None;
         }
-    } else {
-        Some((event, cursor))
     }
 }
 
-/// Returns the index of the drop location among the children of a [`Column`]
-/// at given `position`.
-fn drop_location(layout: &Layout, position: Point) -> usize {
-    let mut index = 0;
-    for item_layout in layout.children() {
-        if position.y < item_layout.bounds().center_y() {
-            break;
-        }
-        index += 1;
+/// An [`Operation`] wrapper that translates the bounds reported to the wrapped operation.
+///
+/// Used to let operations see the on-screen geometry of a dragged child element.
+pub(crate) struct Translate<'a> {
+    pub(crate) operation: &'a mut dyn Operation,
+    pub(crate) translation: Vector,
+}
+
+impl Operation for Translate<'_> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        let translation = self.translation;
+        self.operation
+            .container(id, bounds + translation, &mut |operation| {
+                operate_on_children(&mut Translate {
+                    operation,
+                    translation,
+                });
+            });
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        self.operation.focusable(state, id);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.operation.scrollable(
+            state,
+            id,
+            bounds + self.translation,
+            content_bounds + self.translation,
+            translation,
+        );
+    }
+
+    fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+        self.operation.text(id, bounds + self.translation, text);
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
+        self.operation.custom(state, id);
+    }
+
+    fn finish(&self) -> Outcome<()> {
+        self.operation.finish()
+    }
+}
+
+/// The details of a drop on a [`Column`].
+///
+/// Produced by [`Column::on_drop_event`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DropEvent<Key> {
+    /// The key of the dropped child element.
+    pub key: Key,
+    /// The insertion slot of the drop position among the [`Column`] children.
+    pub index: SlotIndex,
+    /// The number of children in the [`Column`] at the time of the drop.
+    pub len: usize,
+    /// The revision of the order of the children the drop was made on, as set with
+    /// [`Column::revision`].
+    pub revision: u64,
+    /// The keyboard modifiers held at the time of the drop, e.g. to tell moving from
+    /// copying or linking the child element.
+    pub modifiers: keyboard::Modifiers,
+    /// The position the child element was released at.
+    ///
+    /// This is the cursor position, with the horizontal coordinate locked to the grab origin
+    /// unless [`Column::drag_lateral`] is enabled.
+    pub position: Point,
+    /// The bounds of the [`Column`] at the time of the drop, e.g. to tell where exactly
+    /// the child element was released relative to it.
+    pub bounds: Rectangle,
+}
+
+impl<Key> DropEvent<Key> {
+    /// Returns whether the child element was dropped after the last child element.
+    pub fn is_append(&self) -> bool {
+        self.index.0 == self.len
     }
-    index
 }
 
-/// Returns Y-position for drop location marker on the `[Column]`.
-fn drop_location_marker_y(layout: &Layout, spacing: f32, drop_location: usize) -> Option<f32> {
-    if layout.children().count() == 0 {
-        None
-    } else if drop_location < layout.children().count() {
-        let child_bounds_below = layout.children().nth(drop_location).unwrap().bounds();
-        Some(child_bounds_below.y - spacing * 0.5)
-    } else {
-        let last_child_bounds = layout.children().last().unwrap().bounds();
-        Some(last_child_bounds.y + last_child_bounds.height + spacing * 0.5)
+/// A record of a single drop location decision made by a [`Column`].
+///
+/// Produced when [`Column::explain_drops`] is enabled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropExplanation<Key> {
+    /// The key of the dragged child element.
+    pub key: Key,
+    /// The position the decision was made for.
+    ///
+    /// This is the cursor position, with the horizontal coordinate locked to the grab origin
+    /// unless [`Column::drag_lateral`] is enabled.
+    pub position: Point,
+    /// The vertical thresholds separating the candidate drop locations.
+    ///
+    /// A position above the threshold at index `i` resolves to drop location `i`, and a
+    /// position below all thresholds resolves to the number of children.
+    pub thresholds: Vec<f32>,
+    /// The drop location resolved for the previous position, if any.
+    pub previous: Option<usize>,
+    /// The resolved drop location.
+    pub drop_location: usize,
+}
+
+/// The distance a touch or the cursor can move while still being considered a tap or a
+/// click.
+const TAP_TOLERANCE: f32 = 8.0;
+
+/// The longest time between two clicks of a double click.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The width of the gutter reserved for the [`Column::reorder_buttons`].
+const REORDER_BUTTONS_WIDTH: f32 = 16.0;
+
+/// Draws a chevron pointing up or down centered in the given bounds, stacking bars of
+/// decreasing width towards its tip.
+fn draw_chevron<Renderer>(renderer: &mut Renderer, bounds: Rectangle, up: bool, color: Color)
+where
+    Renderer: iced::advanced::Renderer,
+{
+    const BARS: usize = 4;
+    const BAR_HEIGHT: f32 = 1.5;
+
+    let center = bounds.center();
+    let base_width = (bounds.width * 0.6).min(bounds.height * 1.5);
+    let top = center.y - BARS as f32 * BAR_HEIGHT / 2.0;
+
+    for bar in 0..BARS {
+        let from_tip = if up { bar } else { BARS - 1 - bar };
+        let width = base_width * (from_tip + 1) as f32 / BARS as f32;
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x - width / 2.0,
+                    y: top + bar as f32 * BAR_HEIGHT,
+                    width,
+                    height: BAR_HEIGHT,
+                },
+                ..renderer::Quad::default()
+            },
+            color,
+        );
     }
 }
 
+/// The status of a [`Column`], passed to its [`Catalog`] to vary its [`Style`] with the
+/// drag gesture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// No child element is grabbed or dragged.
+    Idle,
+    /// A child element is grabbed, but has not been dragged yet.
+    Grabbed,
+    /// A child element is being dragged.
+    Dragging,
+    /// A child element is being dragged, but cannot be dropped at its drop location, e.g.
+    /// because [`Column::accepts_drop`] rejects it.
+    Invalid,
+}
+
 /// The appearance of of a [`Column`].
+///
+/// See the [`style`] module for presets.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
     /// The color of the drop position marker line indicating drop placement.
     pub color: Color,
+    /// Whether the drop position marker line is dashed.
+    pub dashed: bool,
+    /// The shape of the drop position marker.
+    pub marker: MarkerStyle,
+    /// The highlight of the child element the dragged child element would be dropped onto
+    /// with [`Column::on_drop_onto`] or swapped with in [`DropMode::Swap`].
+    pub target: TargetStyle,
+}
+
+impl Style {
+    /// The default [`Style`], with the primary color of the light built-in theme.
+    ///
+    /// Usable in constants and as the base of custom styles, e.g.
+    /// `Style { dashed: true, ..Style::DEFAULT }`.
+    pub const DEFAULT: Self = Self {
+        color: Color::from_rgb(
+            0x58 as f32 / 255.0,
+            0x65 as f32 / 255.0,
+            0xF2 as f32 / 255.0,
+        ),
+        dashed: false,
+        marker: MarkerStyle::DEFAULT,
+        target: TargetStyle::DEFAULT,
+    };
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The shape of the drop position marker of a [`Style`]: a line across the main axis,
+/// with a circle at its start.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarkerStyle {
+    /// The thickness of the line and of the ring of the circle.
+    pub thickness: f32,
+    /// The corner radius of the line, or of its dashes.
+    pub radius: f32,
+    /// The distance between the start of the line and the start of its bounds, e.g. the
+    /// left edge of the content of a [`Column`].
+    pub start_inset: f32,
+    /// The distance between the end of the line and the end of its bounds.
+    pub end_inset: f32,
+    /// Whether the circle is drawn at the start of the line.
+    pub end_cap: bool,
+    /// The outer radius of the circle.
+    pub cap_radius: f32,
+}
+
+impl MarkerStyle {
+    /// The default [`MarkerStyle`], a 2 px line spanning its bounds with a circle of
+    /// 4 px radius at its start.
+    ///
+    /// Usable in constants and as the base of custom marker styles, e.g.
+    /// `MarkerStyle { end_cap: false, ..MarkerStyle::DEFAULT }`.
+    pub const DEFAULT: Self = Self {
+        thickness: 2.0,
+        radius: 0.0,
+        start_inset: 0.0,
+        end_inset: 0.0,
+        end_cap: true,
+        cap_radius: 4.0,
+    };
+}
+
+impl Default for MarkerStyle {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The highlight of the target child element of a [`Style`], telling dropping onto and
+/// swapping with a child element apart from inserting between the child elements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetStyle {
+    /// The color filling the bounds of the target child element.
+    pub background: Color,
+    /// The color of the outline of the target child element.
+    pub border_color: Color,
+    /// The width of the outline.
+    pub border_width: f32,
+    /// The corner radius of the outline.
+    pub border_radius: f32,
+}
+
+impl TargetStyle {
+    /// The default [`TargetStyle`], with the primary color of the light built-in theme.
+    pub const DEFAULT: Self = Self {
+        background: Color::from_rgba(
+            0x58 as f32 / 255.0,
+            0x65 as f32 / 255.0,
+            0xF2 as f32 / 255.0,
+            0.2,
+        ),
+        border_color: Color::from_rgb(
+            0x58 as f32 / 255.0,
+            0x65 as f32 / 255.0,
+            0xF2 as f32 / 255.0,
+        ),
+        border_width: 2.0,
+        border_radius: 0.0,
+    };
+
+    /// Returns a [`TargetStyle`] outlined with the given color, over a translucent
+    /// background of the same color.
+    pub fn colored(color: Color) -> Self {
+        Self {
+            background: color.scale_alpha(0.2),
+            border_color: color,
+            ..Self::DEFAULT
+        }
+    }
+}
+
+impl Default for TargetStyle {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
 }
 
 /// The theme catalog of a [`Column`].
@@ -893,13 +5241,13 @@ pub trait Catalog: Sized {
     fn default<'a>() -> Self::Class<'a>;
 
     /// The [`Style`] of a class with the given status.
-    fn style(&self, class: &Self::Class<'_>) -> Style;
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
 }
 
 /// A styling function for a [`Column`].
 ///
 /// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
-pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
 
 impl Catalog for Theme {
     type Class<'a> = StyleFn<'a, Self>;
@@ -908,14 +5256,22 @@ impl Catalog for Theme {
         Box::new(default)
     }
 
-    fn style(&self, class: &StyleFn<'_, Self>) -> Style {
-        class(self)
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
     }
 }
 
-/// The default style of a [`Column`].
-pub fn default(theme: &Theme) -> Style {
+/// The default style of a [`Column`], marking rejected drop locations with the danger
+/// color of the theme.
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let color = match status {
+        Status::Invalid => theme.palette().danger,
+        Status::Idle | Status::Grabbed | Status::Dragging => theme.palette().primary,
+    };
+
     Style {
-        color: theme.palette().primary,
+        color,
+        target: TargetStyle::colored(color),
+        ..Style::DEFAULT
     }
 }
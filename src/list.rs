@@ -0,0 +1,255 @@
+//! Own the items of a reorderable list, applying its drag and drop events.
+use crate::index::{move_item, ItemIndex, SlotIndex};
+use crate::{Catalog, Column, ItemStatus};
+
+use iced::Element;
+
+use std::rc::Rc;
+
+/// The items of a reorderable list, viewed as a [`Column`] and reordered by its events.
+///
+/// The [`State`] keeps the index bookkeeping of drops inside the crate: its [`Column`]
+/// is keyed by the indices of the items, and [`State::update`] moves the dropped item
+/// to its new index. The application wraps the [`Event`]s in its own messages and hands
+/// them back.
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::text;
+/// use iced_reorderable::list;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     List(list::Event),
+/// }
+///
+/// struct App {
+///     fruits: list::State<String>,
+/// }
+///
+/// impl App {
+///     fn update(&mut self, message: Message) {
+///         match message {
+///             Message::List(event) => {
+///                 self.fruits.update(event);
+///             }
+///         }
+///     }
+///
+///     fn view(&self) -> iced::Element<'_, Message> {
+///         self.fruits
+///             .view(Message::List, |fruit| text(fruit).into())
+///             .spacing(8)
+///             .into()
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct State<T> {
+    items: Vec<T>,
+    grabbed: Option<usize>,
+}
+
+/// A drag and drop event of the [`Column`] of a [`State`], handled by [`State::update`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// The item at the index was grabbed for dragging.
+    Grabbed(usize),
+    /// The item at the index `from` was dropped in front of the item at the index `to`,
+    /// or after the last item if `to` is the number of items.
+    Dropped {
+        /// The index of the dropped item.
+        from: usize,
+        /// The insertion slot of the drop position.
+        to: usize,
+    },
+    /// The dragging of the item at the index was canceled.
+    Canceled(usize),
+}
+
+impl<T> State<T> {
+    /// Creates a [`State`] owning the given items.
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            items,
+            grabbed: None,
+        }
+    }
+
+    /// Returns the items, in their current order.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the items for editing, e.g. to add or remove items.
+    ///
+    /// Any drag in progress is canceled by the [`Column`] if the grabbed item is removed.
+    pub fn items_mut(&mut self) -> &mut Vec<T> {
+        &mut self.items
+    }
+
+    /// Returns the items, consuming the [`State`].
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Returns the item being dragged, if any.
+    pub fn grabbed(&self) -> Option<&T> {
+        self.grabbed.and_then(|index| self.items.get(index))
+    }
+
+    /// Applies the given [`Event`] of the [`Column`] to the items.
+    ///
+    /// Returns the new index of the dropped item, if the [`Event`] moved an item.
+    pub fn update(&mut self, event: Event) -> Option<usize> {
+        match event {
+            Event::Grabbed(index) => {
+                self.grabbed = Some(index);
+                None
+            }
+            Event::Dropped { from, to } => {
+                self.grabbed = None;
+                let (from, to) = (ItemIndex(from), SlotIndex(to));
+                if from.0 >= self.items.len() || to.is_noop(from) {
+                    return None;
+                }
+                move_item(&mut self.items, from, to);
+
+                Some(to.target(from).0.min(self.items.len() - 1))
+            }
+            Event::Canceled(_) => {
+                self.grabbed = None;
+                None
+            }
+        }
+    }
+
+    /// Creates the [`Column`] of the items, building a child element for each item with
+    /// the given `view` function and producing its [`Event`]s with `on_event`.
+    ///
+    /// The [`Column`] can be configured further before turning it into an [`Element`],
+    /// as long as its drop, grab and cancel messages are left to the [`State`].
+    pub fn view<'a, Message, Theme, Renderer>(
+        &'a self,
+        on_event: impl Fn(Event) -> Message + 'a,
+        view: impl Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Column<'a, usize, Message, Theme, Renderer>
+    where
+        Message: Clone,
+        Theme: Catalog,
+        Renderer: iced::advanced::Renderer,
+    {
+        self.view_with_status(on_event, move |item, _| view(item))
+    }
+
+    /// Creates the [`Column`] of the items like [`State::view`], passing the
+    /// [`ItemStatus`] of each item to the `view` function, e.g. to highlight the dragged
+    /// item.
+    pub fn view_with_status<'a, Message, Theme, Renderer>(
+        &'a self,
+        on_event: impl Fn(Event) -> Message + 'a,
+        view: impl Fn(&'a T, ItemStatus) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Column<'a, usize, Message, Theme, Renderer>
+    where
+        Message: Clone,
+        Theme: Catalog,
+        Renderer: iced::advanced::Renderer,
+    {
+        let on_event = Rc::new(on_event);
+        let (on_grab, on_drop, on_cancel) = (on_event.clone(), on_event.clone(), on_event);
+
+        Column::with_children_status(0..self.items.len(), move |index, status| {
+            view(&self.items[index], status)
+        })
+        .on_grab(move |index| on_grab(Event::Grabbed(index)))
+        .on_drop(move |from, to| on_drop(Event::Dropped { from, to }))
+        .on_cancel(move |index| on_cancel(Event::Canceled(index)))
+    }
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<T> From<Vec<T>> for State<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self::new(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a [`State`] of four items.
+    fn state() -> State<char> {
+        State::new(vec!['a', 'b', 'c', 'd'])
+    }
+
+    #[test]
+    fn grabbed_tracks_the_drag() {
+        let mut state = state();
+        assert_eq!(state.grabbed(), None);
+
+        assert_eq!(state.update(Event::Grabbed(2)), None);
+        assert_eq!(state.grabbed(), Some(&'c'));
+
+        assert_eq!(state.update(Event::Canceled(2)), None);
+        assert_eq!(state.grabbed(), None);
+        assert_eq!(state.items(), ['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    fn drop_moves_the_item_down() {
+        let mut state = state();
+        state.update(Event::Grabbed(0));
+
+        assert_eq!(state.update(Event::Dropped { from: 0, to: 3 }), Some(2));
+        assert_eq!(state.items(), ['b', 'c', 'a', 'd']);
+        assert_eq!(state.grabbed(), None);
+    }
+
+    #[test]
+    fn drop_moves_the_item_up() {
+        let mut state = state();
+
+        assert_eq!(state.update(Event::Dropped { from: 3, to: 1 }), Some(1));
+        assert_eq!(state.items(), ['a', 'd', 'b', 'c']);
+    }
+
+    #[test]
+    fn drop_after_the_last_item() {
+        let mut state = state();
+
+        assert_eq!(state.update(Event::Dropped { from: 0, to: 4 }), Some(3));
+        assert_eq!(state.items(), ['b', 'c', 'd', 'a']);
+
+        // Slots past the end clamp to the last index
+        assert_eq!(state.update(Event::Dropped { from: 0, to: 9 }), Some(3));
+        assert_eq!(state.items(), ['c', 'd', 'a', 'b']);
+    }
+
+    #[test]
+    fn noop_drop_keeps_the_order() {
+        let mut state = state();
+        state.update(Event::Grabbed(1));
+
+        assert_eq!(state.update(Event::Dropped { from: 1, to: 1 }), None);
+        assert_eq!(state.grabbed(), None);
+        assert_eq!(state.update(Event::Dropped { from: 1, to: 2 }), None);
+        assert_eq!(state.items(), ['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    fn drop_of_a_removed_item_is_ignored() {
+        let mut state = state();
+        state.update(Event::Grabbed(3));
+        state.items_mut().truncate(2);
+
+        assert_eq!(state.grabbed(), None);
+        assert_eq!(state.update(Event::Dropped { from: 3, to: 0 }), None);
+        assert_eq!(state.items(), ['a', 'b']);
+    }
+}
@@ -0,0 +1,72 @@
+//! Associate application data with the keys of the reorderable widgets.
+use std::hash::{Hash, Hasher};
+
+/// A key carrying per-item metadata through the callbacks of the reorderable widgets.
+///
+/// Built by [`Column::push_with_meta`](crate::Column::push_with_meta). Every callback
+/// receiving a key receives the [`WithMeta`] of the child element, so message handlers
+/// can read the data of the item directly instead of looking it up by key.
+///
+/// Only the key takes part in comparisons: the children of the widget are identified by
+/// their keys alone, and changing the metadata of a child element between views does not
+/// reset its state or an ongoing drag.
+///
+/// The reorderable widgets require `Copy` keys, so the metadata has to be `Copy` as well,
+/// e.g. a database id, a row index, or a small summary of the item.
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::text;
+/// use iced_reorderable::meta::WithMeta;
+/// use iced_reorderable::Column;
+///
+/// #[derive(Debug, Clone, Copy)]
+/// struct Track {
+///     id: u64,
+///     album: u32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(WithMeta<usize, Track>, usize),
+/// }
+///
+/// fn view(tracks: &[Track]) -> iced::Element<'_, Message> {
+///     tracks
+///         .iter()
+///         .enumerate()
+///         .fold(Column::new(), |column, (index, track)| {
+///             column.push_with_meta(index, *track, text(track.id))
+///         })
+///         .on_drop(Message::Dropped)
+///         .into()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WithMeta<Key, Meta> {
+    /// The key identifying the child element.
+    pub key: Key,
+    /// The metadata associated with the child element.
+    pub meta: Meta,
+}
+
+impl<Key, Meta> WithMeta<Key, Meta> {
+    /// Creates a [`WithMeta`] associating the given metadata with the key.
+    pub fn new(key: Key, meta: Meta) -> Self {
+        Self { key, meta }
+    }
+}
+
+impl<Key: PartialEq, Meta> PartialEq for WithMeta<Key, Meta> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<Key: Eq, Meta> Eq for WithMeta<Key, Meta> {}
+
+impl<Key: Hash, Meta> Hash for WithMeta<Key, Meta> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
@@ -0,0 +1,622 @@
+//! Operate on the reorderable widgets.
+use crate::Items;
+
+use iced::advanced::widget::operation::{Outcome, Scrollable};
+use iced::advanced::widget::{Id, Operation};
+use iced::widget::scrollable::AbsoluteOffset;
+use iced::{Rectangle, Vector};
+
+/// Produces an [`Operation`] that scrolls the innermost scrollable containing the child
+/// element with the given key, so that the child element becomes fully visible.
+///
+/// Nothing is scrolled if the child element is already visible. Run the operation after
+/// a drop to keep the dropped child element in view at its new position; keep in mind
+/// that the key of the dropped child element may have changed, e.g. if indices are
+/// used as keys.
+///
+/// Child elements picked up and moved with the arrow keys are kept in view by the
+/// [`Column`](crate::Column) itself, through [`Column::on_auto_scroll`] messages or a
+/// [`ReorderableScrollable`]. Run the operation after moves made by the application
+/// instead, e.g. with its own shortcuts, so that the moved child element stays in view;
+/// this matters when the view is magnified and only a small part of the list is visible.
+///
+/// [`Column::on_auto_scroll`]: crate::Column::on_auto_scroll
+/// [`ReorderableScrollable`]: crate::ReorderableScrollable
+pub fn reveal<Key, T>(key: Key) -> impl Operation<T>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    Reveal {
+        key,
+        pending: None,
+        scrollables: Vec::new(),
+        target: None,
+    }
+}
+
+/// The viewport of a scrollable visited by an [`Operation`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Viewport {
+    pub(crate) bounds: Rectangle,
+    pub(crate) content_bounds: Rectangle,
+    pub(crate) translation: Vector,
+}
+
+impl Viewport {
+    /// Returns the offset of the [`Viewport`] scrolled by the given amount within the limits
+    /// of its content, if it changes.
+    pub(crate) fn scrolled_by(&self, delta: Vector) -> Option<AbsoluteOffset> {
+        let max_x = (self.content_bounds.width - self.bounds.width).max(0.0);
+        let max_y = (self.content_bounds.height - self.bounds.height).max(0.0);
+        let offset = AbsoluteOffset {
+            x: (self.translation.x + delta.x).clamp(0.0, max_x),
+            y: (self.translation.y + delta.y).clamp(0.0, max_y),
+        };
+
+        (offset.x != self.translation.x || offset.y != self.translation.y).then_some(offset)
+    }
+
+    /// Returns the offset that makes the given bounds fully visible in the [`Viewport`],
+    /// if it needs to be scrolled.
+    pub(crate) fn offset_to_reveal(&self, target: Rectangle) -> Option<AbsoluteOffset> {
+        let reveal = |start: f32, length: f32, visible_start: f32, visible_length: f32| {
+            if start < visible_start {
+                start
+            } else if start + length > visible_start + visible_length {
+                (start + length - visible_length).min(start)
+            } else {
+                visible_start
+            }
+        };
+
+        let x = reveal(
+            target.x - self.content_bounds.x,
+            target.width,
+            self.translation.x,
+            self.bounds.width,
+        );
+        let y = reveal(
+            target.y - self.content_bounds.y,
+            target.height,
+            self.translation.y,
+            self.bounds.height,
+        );
+
+        (x != self.translation.x || y != self.translation.y).then_some(AbsoluteOffset { x, y })
+    }
+
+    /// Returns the offset that centers the given bounds in the [`Viewport`] as far as its
+    /// content allows, if it needs to be scrolled.
+    fn offset_to_center(&self, target: Rectangle) -> Option<AbsoluteOffset> {
+        let center = target.center() - self.content_bounds.position();
+        let half = Vector::new(self.bounds.width * 0.5, self.bounds.height * 0.5);
+
+        self.scrolled_by(center - half - self.translation)
+    }
+}
+
+/// The [`Operation`] produced by [`reveal`].
+///
+/// Tracks the scrollables enclosing the visited children, where `pending` is a visited
+/// scrollable whose content has not been visited yet.
+struct Reveal<Key> {
+    key: Key,
+    pending: Option<Viewport>,
+    scrollables: Vec<Viewport>,
+    target: Option<(Viewport, Rectangle)>,
+}
+
+impl<Key, T> Operation<T> for Reveal<Key>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        // The container visited right after a scrollable holds its content
+        let scrollable = self.pending.take();
+        if let Some(viewport) = scrollable {
+            self.scrollables.push(viewport);
+        }
+        operate_on_children(self);
+        if scrollable.is_some() {
+            let _ = self.scrollables.pop();
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.pending = Some(Viewport {
+            bounds,
+            content_bounds,
+            translation,
+        });
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, _id: Option<&Id>) {
+        let Some(items) = state.downcast_mut::<Items<Key>>() else {
+            return;
+        };
+        if let Some((_, bounds)) = items.bounds.iter().find(|(key, _)| *key == self.key) {
+            if let Some(viewport) = self.scrollables.last() {
+                self.target = Some((*viewport, *bounds));
+            }
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        match self.target {
+            Some((viewport, bounds)) => match viewport.offset_to_reveal(bounds) {
+                Some(offset) => Outcome::Chain(Box::new(ScrollViewport {
+                    bounds: viewport.bounds,
+                    offset,
+                })),
+                None => Outcome::None,
+            },
+            None => Outcome::None,
+        }
+    }
+}
+
+/// Produces an [`Operation`] that scrolls the innermost scrollable containing the
+/// [`Column`](crate::Column) with the given [`Id`], so that its child element with the
+/// given key is centered in view as far as the content allows.
+///
+/// Unlike [`reveal`], the child element is scrolled to even if it is already visible,
+/// e.g. to jump to the currently playing track of a long playlist.
+pub fn scroll_to<Key, T>(id: Id, key: Key) -> impl Operation<T>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    ScrollTo {
+        id,
+        key,
+        pending: None,
+        scrollables: Vec::new(),
+        target: None,
+    }
+}
+
+/// The [`Operation`] produced by [`scroll_to`], tracking the enclosing scrollables like
+/// [`Reveal`].
+struct ScrollTo<Key> {
+    id: Id,
+    key: Key,
+    pending: Option<Viewport>,
+    scrollables: Vec<Viewport>,
+    target: Option<(Viewport, Rectangle)>,
+}
+
+impl<Key, T> Operation<T> for ScrollTo<Key>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let scrollable = self.pending.take();
+        if let Some(viewport) = scrollable {
+            self.scrollables.push(viewport);
+        }
+        operate_on_children(self);
+        if scrollable.is_some() {
+            let _ = self.scrollables.pop();
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.pending = Some(Viewport {
+            bounds,
+            content_bounds,
+            translation,
+        });
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
+        if id != Some(&self.id) || self.target.is_some() {
+            return;
+        }
+        let Some(items) = state.downcast_mut::<Items<Key>>() else {
+            return;
+        };
+        if let Some((_, bounds)) = items.bounds.iter().find(|(key, _)| *key == self.key) {
+            if let Some(viewport) = self.scrollables.last() {
+                self.target = Some((*viewport, *bounds));
+            }
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        let Some((viewport, offset)) = self
+            .target
+            .and_then(|(viewport, bounds)| Some((viewport, viewport.offset_to_center(bounds)?)))
+        else {
+            return Outcome::None;
+        };
+
+        Outcome::Chain(Box::new(ScrollViewport {
+            bounds: viewport.bounds,
+            offset,
+        }))
+    }
+}
+
+/// Scrolls the scrollable with the given bounds to the given offset.
+pub(crate) struct ScrollViewport {
+    pub(crate) bounds: Rectangle,
+    pub(crate) offset: AbsoluteOffset,
+}
+
+impl<T> Operation<T> for ScrollViewport {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        if bounds == self.bounds {
+            state.scroll_to(self.offset);
+        }
+    }
+}
+
+/// Produces an [`Operation`] that scrolls the innermost scrollable containing the
+/// [`Column`](crate::Column) with the given [`Id`] by the given amount, within the limits
+/// of its content.
+///
+/// Run the operation with the amount produced by
+/// [`Column::on_auto_scroll`](crate::Column::on_auto_scroll) to scroll a long list while
+/// dragging near its edges.
+pub fn scroll_by<T>(id: Id, delta: Vector) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    ScrollBy {
+        id,
+        delta,
+        pending: None,
+        scrollables: Vec::new(),
+        target: None,
+    }
+}
+
+/// The [`Operation`] produced by [`scroll_by`], tracking the enclosing scrollables like
+/// [`Reveal`].
+struct ScrollBy {
+    id: Id,
+    delta: Vector,
+    pending: Option<Viewport>,
+    scrollables: Vec<Viewport>,
+    target: Option<Viewport>,
+}
+
+impl<T> Operation<T> for ScrollBy
+where
+    T: Send + 'static,
+{
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let scrollable = self.pending.take();
+        if let Some(viewport) = scrollable {
+            self.scrollables.push(viewport);
+        }
+        operate_on_children(self);
+        if scrollable.is_some() {
+            let _ = self.scrollables.pop();
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.pending = Some(Viewport {
+            bounds,
+            content_bounds,
+            translation,
+        });
+    }
+
+    fn custom(&mut self, _state: &mut dyn std::any::Any, id: Option<&Id>) {
+        if id == Some(&self.id) && self.target.is_none() {
+            self.target = self.scrollables.last().copied();
+        }
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        let Some((viewport, offset)) = self
+            .target
+            .and_then(|viewport| Some((viewport, viewport.scrolled_by(self.delta)?)))
+        else {
+            return Outcome::None;
+        };
+
+        Outcome::Chain(Box::new(ScrollViewport {
+            bounds: viewport.bounds,
+            offset,
+        }))
+    }
+}
+
+/// Produces an [`Operation`] that picks up the child element with the given key of the
+/// [`Column`](crate::Column) with the given [`Id`], starting a move without a pointer
+/// gesture, e.g. from a "Move this item…" context menu entry.
+///
+/// The child element is picked up as with
+/// [`Column::click_to_drag`](crate::Column::click_to_drag): the subsequent pointer
+/// movement or the arrow keys choose the destination, and the next press or Enter drops
+/// it. Escape and right-clicking cancel the move. Placeholders cannot be picked up.
+pub fn grab<Key, T>(id: Id, key: Key) -> impl Operation<T>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    Grab { id, key }
+}
+
+/// The [`Operation`] produced by [`grab`].
+struct Grab<Key> {
+    id: Id,
+    key: Key,
+}
+
+impl<Key, T> Operation<T> for Grab<Key>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
+        if id != Some(&self.id) {
+            return;
+        }
+        if let Some(items) = state.downcast_mut::<Items<Key>>() {
+            items.grab = Some(self.key);
+        }
+    }
+}
+
+/// Produces an [`Operation`] that focuses the child element with the given key of the
+/// [`Column`](crate::Column) with the given [`Id`], unfocusing any other focused child
+/// element of it.
+///
+/// The focused child element can be picked up with Space and moved with the arrow keys,
+/// like one reached with the focus operations. Placeholders and pinned child elements
+/// cannot be focused.
+pub fn focus<Key, T>(id: Id, key: Key) -> impl Operation<T>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    Focus { id, key }
+}
+
+/// The [`Operation`] produced by [`focus`].
+struct Focus<Key> {
+    id: Id,
+    key: Key,
+}
+
+impl<Key, T> Operation<T> for Focus<Key>
+where
+    Key: Copy + PartialEq + Send + 'static,
+    T: Send + 'static,
+{
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
+        if id != Some(&self.id) {
+            return;
+        }
+        if let Some(items) = state.downcast_mut::<Items<Key>>() {
+            items.focus = Some(self.key);
+        }
+    }
+}
+
+/// The layout of the children of a [`Column`](crate::Column), produced by [`geometry`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Geometry<Key> {
+    /// The bounds of the [`Column`](crate::Column).
+    pub bounds: Rectangle,
+    /// The layout of each child element, in order.
+    pub items: Vec<ItemGeometry<Key>>,
+}
+
+/// The layout of a single child element of a [`Column`](crate::Column).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ItemGeometry<Key> {
+    /// The key of the child element.
+    pub key: Key,
+    /// The vertical offset of the child element from the top of the
+    /// [`Column`](crate::Column).
+    pub y: f32,
+    /// The height of the child element.
+    pub height: f32,
+}
+
+impl<Key> Geometry<Key> {
+    /// Returns the number of child elements.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether the [`Column`](crate::Column) has no child elements.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+/// Produces an [`Operation`] that returns the [`Geometry`] of the children of the
+/// [`Column`](crate::Column) with the given [`Id`].
+///
+/// The geometry reflects the actual layout of the [`Column`](crate::Column), making it
+/// possible to render minimaps or annotated scrollbars in sync with it.
+pub fn geometry<Key>(id: Id) -> impl Operation<Geometry<Key>>
+where
+    Key: Copy + Send + 'static,
+{
+    GeometryOf { id, geometry: None }
+}
+
+/// The [`Operation`] produced by [`geometry`].
+struct GeometryOf<Key> {
+    id: Id,
+    geometry: Option<Geometry<Key>>,
+}
+
+impl<Key> Operation<Geometry<Key>> for GeometryOf<Key>
+where
+    Key: Copy + Send + 'static,
+{
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Geometry<Key>>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
+        if id != Some(&self.id) {
+            return;
+        }
+        let Some(items) = state.downcast_mut::<Items<Key>>() else {
+            return;
+        };
+        self.geometry = Some(Geometry {
+            bounds: items.column,
+            items: items
+                .bounds
+                .iter()
+                .map(|(key, bounds)| ItemGeometry {
+                    key: *key,
+                    y: bounds.y - items.column.y,
+                    height: bounds.height,
+                })
+                .collect(),
+        });
+    }
+
+    fn finish(&self) -> Outcome<Geometry<Key>> {
+        match &self.geometry {
+            Some(geometry) => Outcome::Some(geometry.clone()),
+            None => Outcome::None,
+        }
+    }
+}
+
+/// The drag in progress on a [`Column`](crate::Column), produced by [`drag_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DragInfo<Key> {
+    /// The key of the grabbed child element.
+    pub key: Key,
+    /// The drop location the child element would be dropped at, or `None` while it has
+    /// been grabbed but not dragged past the drag threshold yet.
+    pub drop_location: Option<usize>,
+}
+
+/// Produces an [`Operation`] that returns the drag in progress on the
+/// [`Column`](crate::Column) with the given [`Id`], or `None` if it is idle.
+///
+/// Useful outside of the drag messages, e.g. to disable a toolbar while dragging.
+pub fn drag_state<Key>(id: Id) -> impl Operation<Option<DragInfo<Key>>>
+where
+    Key: Copy + Send + 'static,
+{
+    DragStateOf { id, drag: None }
+}
+
+/// The [`Operation`] produced by [`drag_state`].
+struct DragStateOf<Key> {
+    id: Id,
+    drag: Option<Option<DragInfo<Key>>>,
+}
+
+impl<Key> Operation<Option<DragInfo<Key>>> for DragStateOf<Key>
+where
+    Key: Copy + Send + 'static,
+{
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Option<DragInfo<Key>>>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
+        if id != Some(&self.id) {
+            return;
+        }
+        if let Some(items) = state.downcast_mut::<Items<Key>>() {
+            self.drag = Some(items.drag);
+        }
+    }
+
+    fn finish(&self) -> Outcome<Option<DragInfo<Key>>> {
+        match self.drag {
+            Some(drag) => Outcome::Some(drag),
+            None => Outcome::None,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Carry application data along with dragged child elements.
+use std::any::{self, Any};
+use std::fmt;
+use std::rc::Rc;
+
+/// Type-erased data attached to a drag, readable by any drop target of the drag.
+///
+/// The key of a child element is only meaningful to the widget it belongs to. A
+/// [`Column`](crate::Column) of a [`DragGroup`](crate::DragGroup) can attach a [`Payload`]
+/// to each of its drags with [`Column::payload`](crate::Column::payload), e.g. the item
+/// itself or an id understood by the whole application, which the other members of the
+/// group receive with the drop, see
+/// [`Column::on_transfer_payload`](crate::Column::on_transfer_payload) and
+/// [`TrashZone::on_delete_payload`](crate::TrashZone::on_delete_payload). Custom drop
+/// targets can read the payload of the drag in progress with
+/// [`DragGroup::payload`](crate::DragGroup::payload).
+///
+/// Cloning a [`Payload`] is cheap, the data is shared.
+///
+/// # Example
+/// ```
+/// use iced_reorderable::payload::Payload;
+///
+/// let payload = Payload::new(String::from("Apple"));
+///
+/// assert_eq!(payload.downcast_ref::<String>().map(String::as_str), Some("Apple"));
+/// assert!(payload.downcast_ref::<u64>().is_none());
+/// ```
+#[derive(Clone)]
+pub struct Payload {
+    value: Rc<dyn Any>,
+    type_name: &'static str,
+}
+
+impl Payload {
+    /// Creates a [`Payload`] carrying the given value.
+    pub fn new<T: Any>(value: T) -> Self {
+        Self {
+            value: Rc::new(value),
+            type_name: any::type_name::<T>(),
+        }
+    }
+
+    /// Returns a reference to the carried value, if it is of type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    /// Returns whether the carried value is of type `T`.
+    pub fn is<T: Any>(&self) -> bool {
+        self.value.is::<T>()
+    }
+}
+
+impl fmt::Debug for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Payload").field(&self.type_name).finish()
+    }
+}
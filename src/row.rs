@@ -0,0 +1,844 @@
+//! Lay out keyed children in a single horizontal line, and reorder them by dragging and
+//! dropping.
+use crate::flex::{self, DragState};
+use crate::{Catalog, ItemFocus, Items, Status, Style, StyleFn, Translate};
+
+use iced::advanced::layout;
+use iced::advanced::layout::flex::Axis;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Id, Operation, Tree, Widget};
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::alignment::{self, Alignment};
+use iced::mouse;
+use iced::time::{Duration, Instant};
+use iced::touch;
+use iced::Event;
+use iced::{Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Size, Vector};
+
+/// A container that distributes its keyed children horizontally and allows reordering
+/// them by dragging and dropping.
+///
+/// The [`Row`] has its own drag state, independent of any [`Column`](crate::Column) it is
+/// combined with. This makes it suitable for reorderable table column headers, with the
+/// rows of the table in a [`Column`](crate::Column): the two keyed lists are reordered
+/// along different axes without interfering with each other.
+///
+/// Like for a [`Column`](crate::Column), the child elements of a [`Row`] producing drop
+/// messages take part in the focus operations: the focused child element is outlined,
+/// Space picks it up, the left and right arrow keys move it and Enter drops it.
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::{column, text};
+/// use iced_reorderable::{Column, Row};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ColumnDropped(usize, usize),
+///     RowDropped(usize, usize),
+/// }
+///
+/// fn view<'a>(headers: &'a [String], rows: &'a [String]) -> iced::Element<'a, Message> {
+///     let header = Row::with_children(
+///         headers
+///             .iter()
+///             .enumerate()
+///             .map(|(index, header)| (index, text(header).width(120).into())),
+///     )
+///     .on_column_drop(Message::ColumnDropped);
+///
+///     let rows = Column::with_children(
+///         rows.iter()
+///             .enumerate()
+///             .map(|(index, row)| (index, text(row).into())),
+///     )
+///     .on_drop(Message::RowDropped);
+///
+///     column![header, rows].into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Row<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    align: Alignment,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    keys: Vec<Key>,
+    class: Theme::Class<'a>,
+    on_drag: Option<Box<dyn Fn(Key, usize) -> Message + 'a>>,
+    on_drop: Option<Box<dyn Fn(Key, usize) -> Message + 'a>>,
+    on_cancel: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_auto_scroll: Option<Box<dyn Fn(Vector) -> Message + 'a>>,
+    drop_position_marker: bool,
+    drag_follow: bool,
+    id: Option<Id>,
+    mouse_hold_delay: Option<Duration>,
+    clock: flex::TimeSource<'a>,
+    keyboard_wrap: bool,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Row<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates an empty [`Row`].
+    pub fn new() -> Self {
+        Self {
+            spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            align: Alignment::Start,
+            children: Vec::new(),
+            keys: Vec::new(),
+            class: Theme::default(),
+            on_drag: None,
+            on_drop: None,
+            on_cancel: None,
+            on_auto_scroll: None,
+            drop_position_marker: true,
+            drag_follow: false,
+            id: None,
+            mouse_hold_delay: None,
+            clock: flex::TimeSource::default(),
+            keyboard_wrap: false,
+        }
+    }
+
+    /// Creates a [`Row`] with the given keys and elements.
+    pub fn with_children(
+        children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        Self::new().extend(children)
+    }
+
+    /// Sets the horizontal spacing _between_ elements.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Row`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Row`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Row`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Row`].
+    pub fn align_y(mut self, align: impl Into<alignment::Vertical>) -> Self {
+        self.align = Alignment::from(align.into());
+        self
+    }
+
+    /// Adds an element to the [`Row`].
+    pub fn push(
+        mut self,
+        key: Key,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let child = child.into();
+        let child_size = child.as_widget().size_hint();
+
+        self.width = self.width.enclose(child_size.width);
+        self.height = self.height.enclose(child_size.height);
+
+        self.keys.push(key);
+        self.children.push(child);
+        self
+    }
+
+    /// Extends the [`Row`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        children
+            .into_iter()
+            .fold(self, |items, (key, child)| items.push(key, child))
+    }
+
+    /// Sets the style of the [`Row`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Row`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the message that will be produced when dragging starts or the dragged child
+    /// element has been dragged to another position in the [`Row`].
+    ///
+    /// The message will be produced with the key of the dragged child element and the index
+    /// of the drag position among the [`Row`] children.
+    pub fn on_drag<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, usize) -> Message + 'a,
+    {
+        self.on_drag = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when the dragged child element is dropped on
+    /// the [`Row`].
+    ///
+    /// The message will be produced with the key of the dragged child element and the index
+    /// of the drop position among the [`Row`] children.
+    pub fn on_drop<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, usize) -> Message + 'a,
+    {
+        self.on_drop = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when a table column header is dropped on
+    /// the [`Row`], for [`Row`]s holding the reorderable column headers of a table.
+    ///
+    /// This is the same message as [`Row::on_drop`], produced with the key of the dropped
+    /// column and the index of its drop position among the columns.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use iced::widget::text;
+    /// use iced_reorderable::index::{move_item, ItemIndex, SlotIndex};
+    /// use iced_reorderable::Row;
+    ///
+    /// #[derive(Debug, Clone, Copy, PartialEq)]
+    /// enum Field {
+    ///     Name,
+    ///     Size,
+    ///     Modified,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     HeaderDropped(Field, usize),
+    /// }
+    ///
+    /// fn update(fields: &mut Vec<Field>, message: Message) {
+    ///     let Message::HeaderDropped(field, slot) = message;
+    ///     if let Some(from) = fields.iter().position(|other| *other == field) {
+    ///         move_item(fields, ItemIndex(from), SlotIndex(slot));
+    ///     }
+    /// }
+    ///
+    /// fn view(fields: &[Field]) -> iced::Element<'_, Message> {
+    ///     Row::with_children(
+    ///         fields
+    ///             .iter()
+    ///             .map(|field| (*field, text(format!("{field:?}")).width(120).into())),
+    ///     )
+    ///     .on_column_drop(Message::HeaderDropped)
+    ///     .into()
+    /// }
+    /// ```
+    pub fn on_column_drop<F>(self, message: F) -> Self
+    where
+        F: Fn(Key, usize) -> Message + 'a,
+    {
+        self.on_drop(message)
+    }
+
+    /// Sets the message that will be produced when the user cancels active dragging by
+    /// right-clicking, by pressing Escape, or when the dragging touch is lost. Active
+    /// dragging is canceled as well when the window loses focus.
+    pub fn on_cancel<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_cancel = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced on every frame while a child element is
+    /// dragged near the left or right edge of the viewport, when the [`Row`] is put in a
+    /// horizontal [`Scrollable`](iced::widget::Scrollable) and only part of it is in view.
+    ///
+    /// The message will be produced with the amount to scroll by, like
+    /// [`Column::on_auto_scroll`](crate::Column::on_auto_scroll).
+    pub fn on_auto_scroll<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Vector) -> Message + 'a,
+    {
+        self.on_auto_scroll = Some(Box::new(message));
+        self
+    }
+
+    /// Sets whether a marker line will be shown at the position where the dragged child
+    /// element would be dropped.
+    pub fn drop_position_marker(mut self, drop_position_marker: bool) -> Self {
+        self.drop_position_marker = drop_position_marker;
+        self
+    }
+
+    /// Sets whether a child element should follow the cursor or touch while being dragged.
+    pub fn drag_follow(mut self, drag_follow: bool) -> Self {
+        self.drag_follow = drag_follow;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Row`], to target it with the operations of the
+    /// [`operation`](crate::operation) module, e.g. to scroll to or focus a child element.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets how long the mouse button has to be held on a child element before it can be
+    /// dragged, like [`Column::mouse_hold_delay`](crate::Column::mouse_hold_delay).
+    pub fn mouse_hold_delay(mut self, mouse_hold_delay: Option<Duration>) -> Self {
+        self.mouse_hold_delay = mouse_hold_delay;
+        self
+    }
+
+    /// Sets the clock the [`Row`] reads the current time from for the
+    /// [`Row::mouse_hold_delay`], like [`Column::clock`](crate::Column::clock).
+    pub fn clock(mut self, clock: impl Fn() -> Instant + 'a) -> Self {
+        self.clock = flex::TimeSource::new(clock);
+        self
+    }
+
+    /// Sets whether moving a picked up child element with the arrow keys should wrap
+    /// around the ends of the [`Row`], like
+    /// [`Column::keyboard_wrap`](crate::Column::keyboard_wrap).
+    pub fn keyboard_wrap(mut self, keyboard_wrap: bool) -> Self {
+        self.keyboard_wrap = keyboard_wrap;
+        self
+    }
+
+    /// Returns the key of the child element following the cursor and its translation
+    /// from its laid out position, if any.
+    fn dragged_translation(&self, state: &State<Key>, layout: Layout<'_>) -> Option<(Key, Vector)> {
+        state
+            .drag
+            .follow_translation(self.keys.iter().zip(layout.children()))
+            .filter(|_| self.drag_follow)
+            .map(|(key, translation)| (key, Vector::new(translation.x, 0.0)))
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Default for Row<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> flex::Gesture<Key, Message>
+    for Row<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    type State = State<Key>;
+
+    fn drag<'s>(&self, state: &'s mut State<Key>) -> &'s mut DragState<Key> {
+        &mut state.drag
+    }
+
+    fn grab_at(
+        &mut self,
+        _state: &mut State<Key>,
+        _shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        _event: &Event,
+        position: Point,
+    ) -> Option<(Key, Rectangle)> {
+        flex::child_at(&self.keys, layout, position)
+    }
+
+    fn drop_location(&self, layout: Layout<'_>, _key: Key, position: Point) -> usize {
+        flex::drop_location(&Axis::Horizontal, &layout, position)
+    }
+
+    fn drag_message(&self, key: Key, drop_location: usize) -> Option<Message> {
+        self.on_drag
+            .as_ref()
+            .map(|on_drag| on_drag(key, drop_location))
+    }
+
+    fn drop_message(&self, key: Key, drop_location: usize) -> Option<Message> {
+        self.on_drop
+            .as_ref()
+            .map(|on_drop| on_drop(key, drop_location))
+    }
+
+    fn cancel_message(&self, key: Key) -> Option<Message> {
+        self.on_cancel.as_ref().map(|on_cancel| on_cancel(key))
+    }
+
+    fn hold(&self) -> Option<flex::Hold> {
+        self.clock.hold(self.mouse_hold_delay)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> flex::PickUp<Key, Message>
+    for Row<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn picked_up<'s>(&self, state: &'s mut State<Key>) -> &'s mut bool {
+        &mut state.picked_up
+    }
+
+    fn focused(&self, state: &State<Key>) -> Option<Key> {
+        state.focused
+    }
+
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    fn keyboard_wrap(&self) -> bool {
+        self.keyboard_wrap
+    }
+
+    fn pick_up(
+        &self,
+        state: &mut State<Key>,
+        _shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        key: Key,
+    ) -> bool {
+        flex::pick_up(&mut state.drag, &layout, &self.keys, key, |position| {
+            flex::drop_location(&Axis::Horizontal, &layout, position)
+        })
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Row<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key>::default())
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        if state
+            .drag
+            .key()
+            .is_some_and(|key| !self.keys.contains(&key))
+        {
+            state.drag = DragState::Idle;
+        }
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::flex::resolve(
+            Axis::Horizontal,
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            self.padding,
+            self.spacing,
+            self.align,
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let drag_state = tree.state.downcast_ref::<State<Key>>().drag;
+        if let Some((event, cursor)) =
+            flex::propagate_event_to_children(&drag_state, 0.0, event, cursor)
+        {
+            for ((child, state), item_layout) in self
+                .children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget_mut().update(
+                    state,
+                    event,
+                    item_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) && state.focused.take().is_some()
+        {
+            shell.request_redraw();
+        }
+        flex::update_picked_up(
+            &Axis::Horizontal,
+            self,
+            state,
+            event,
+            layout,
+            cursor,
+            shell,
+            viewport,
+        );
+        flex::update(self, state, event, layout, cursor, shell);
+        if state.drag.is_idle() {
+            state.picked_up = false;
+        }
+        if let Some(on_auto_scroll) = self.on_auto_scroll.as_deref() {
+            flex::auto_scroll(
+                &Axis::Horizontal,
+                self,
+                state,
+                event,
+                layout,
+                cursor,
+                shell,
+                viewport,
+                on_auto_scroll,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let mut items = Items {
+            column: layout.bounds(),
+            bounds: self
+                .keys
+                .iter()
+                .copied()
+                .zip(layout.children().map(|item_layout| item_layout.bounds()))
+                .collect(),
+            grab: None,
+            focus: None,
+            dragging: matches!(state.drag, DragState::Dragged { .. }),
+            drag: state.drag.info(),
+        };
+        operation.custom(&mut items, self.id.as_ref());
+        if let Some(key) = items.grab {
+            state.picked_up =
+                flex::pick_up(&mut state.drag, &layout, &self.keys, key, |position| {
+                    flex::drop_location(&Axis::Horizontal, &layout, position)
+                });
+        }
+        if let Some(key) = items.focus.filter(|key| self.keys.contains(key)) {
+            state.focused = Some(key);
+        }
+
+        let dragged = self.dragged_translation(state, layout);
+        let is_focusable = self.on_drop.is_some();
+        let row_state = tree.state.downcast_mut::<State<Key>>();
+
+        operation.container(self.id.as_ref(), layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&self.keys)
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|(((child, key), state), item_layout)| {
+                    // Expose the child elements to the focus operations
+                    if is_focusable {
+                        operation.focusable(
+                            &mut ItemFocus {
+                                key: *key,
+                                focused: &mut row_state.focused,
+                            },
+                            None,
+                        );
+                    }
+                    match dragged {
+                        Some((dragged_key, translation)) if dragged_key == *key => {
+                            child.as_widget().operate(
+                                state,
+                                item_layout,
+                                renderer,
+                                &mut Translate {
+                                    operation,
+                                    translation,
+                                },
+                            );
+                        }
+                        _ => {
+                            child
+                                .as_widget()
+                                .operate(state, item_layout, renderer, operation);
+                        }
+                    }
+                });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let drag_state = tree.state.downcast_ref::<State<Key>>().drag;
+        if !drag_state.is_idle() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), item_layout)| {
+                let child_interaction = child.as_widget().mouse_interaction(
+                    state,
+                    item_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                );
+                if self.on_drop.is_some() && cursor.is_over(item_layout.bounds()) {
+                    mouse::Interaction::Pointer.max(child_interaction)
+                } else {
+                    child_interaction
+                }
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(clipped_viewport) = layout.bounds().intersection(viewport) else {
+            return;
+        };
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let (dragged_key, dragged_translation) = match self.dragged_translation(state, layout) {
+            Some((key, translation)) => (Some(key), translation),
+            None => (None, Vector::ZERO),
+        };
+
+        let marker = match state.drag {
+            DragState::Dragged { drop_location, .. } if self.drop_position_marker => {
+                flex::marker_bounds(
+                    &Axis::Horizontal,
+                    &layout,
+                    self.padding,
+                    self.spacing,
+                    drop_location,
+                )
+            }
+            _ => None,
+        };
+
+        let focused_layout = state
+            .focused
+            .filter(|key| state.drag.key() != Some(*key))
+            .and_then(|key| self.keys.iter().position(|item_key| *item_key == key))
+            .and_then(|index| layout.children().nth(index));
+
+        let mut dragged = None;
+        for (((child, key), state), item_layout) in self
+            .children
+            .iter()
+            .zip(&self.keys)
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|(_, item_layout)| item_layout.bounds().intersects(viewport))
+        {
+            if Some(*key) == dragged_key {
+                dragged = Some((child, state, item_layout));
+                continue;
+            }
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, item_layout, cursor, viewport);
+        }
+
+        if let Some(item_layout) = focused_layout {
+            let color = theme.style(&self.class, state.drag.status()).color;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: item_layout.bounds(),
+                    border: Border {
+                        color,
+                        width: flex::MARKER_LINE_WIDTH,
+                        radius: 2.0.into(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        if marker.is_some() || dragged.is_some() {
+            renderer.with_layer(clipped_viewport, |renderer| {
+                if let Some(marker) = marker {
+                    let marker_style = theme.style(&self.class, state.drag.status());
+                    flex::draw_marker(renderer, &Axis::Horizontal, marker, viewport, marker_style);
+                }
+                if let Some((child, state, item_layout)) = dragged {
+                    renderer.with_translation(dragged_translation, |renderer| {
+                        child.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            style,
+                            item_layout,
+                            cursor,
+                            viewport,
+                        );
+                    });
+                }
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((child, state), item_layout)| {
+                child
+                    .as_widget_mut()
+                    .overlay(state, item_layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<Row<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: Row<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct State<K>
+where
+    K: Copy + PartialEq,
+{
+    drag: DragState<K>,
+    /// Whether the dragged child element has been picked up with the keyboard or an
+    /// operation, without holding a button.
+    picked_up: bool,
+    /// The key of the child element focused by a focus operation, e.g. with Tab.
+    focused: Option<K>,
+}
+
+impl<Key> Default for State<Key>
+where
+    Key: Copy + PartialEq,
+{
+    fn default() -> Self {
+        Self {
+            drag: DragState::Idle,
+            picked_up: false,
+            focused: None,
+        }
+    }
+}
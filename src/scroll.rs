@@ -0,0 +1,449 @@
+//! Scroll a reorderable column by itself while dragging its children.
+use crate::flex;
+use crate::operation::{DragInfo, ScrollViewport, Viewport};
+use crate::{Catalog, Column, Items};
+
+use iced::advanced::layout;
+use iced::advanced::layout::flex::Axis;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::operation::Scrollable as ScrollableState;
+use iced::advanced::widget::{tree, Id, Operation, Tree, Widget};
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::keyboard;
+use iced::mouse;
+use iced::touch;
+use iced::widget::scrollable;
+use iced::widget::Scrollable;
+use iced::window;
+use iced::Event;
+use iced::{Element, Length, Rectangle, Size, Vector};
+
+use std::marker::PhantomData;
+
+/// A [`Scrollable`] holding a reorderable [`Column`], which scrolls by itself while a
+/// child element is dragged near its edges.
+///
+/// The [`ReorderableScrollable`] takes care of the wiring a [`Column`] in a plain
+/// [`Scrollable`] needs: it scrolls without [`Column::on_auto_scroll`] messages or
+/// scrollable [`Id`]s, and keeps the drop location under the cursor up to date when the
+/// content scrolls beneath it, be it automatically or with the mouse wheel.
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::text;
+/// use iced_reorderable::{Column, ReorderableScrollable};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(usize, usize),
+/// }
+///
+/// fn view(items: &[String]) -> iced::Element<'_, Message> {
+///     let column = Column::with_children(
+///         items
+///             .iter()
+///             .enumerate()
+///             .map(|(index, item)| (index, text(item).into())),
+///     )
+///     .on_drop(Message::Dropped);
+///
+///     ReorderableScrollable::new(column).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ReorderableScrollable<'a, Key, Message, Theme, Renderer>
+where
+    Theme: scrollable::Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    scrollable: Scrollable<'a, Message, Theme, Renderer>,
+    key: PhantomData<Key>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> ReorderableScrollable<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: Clone + 'a,
+    Theme: Catalog + scrollable::Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    /// Creates a [`ReorderableScrollable`] holding the given [`Column`].
+    pub fn new(column: Column<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self {
+            scrollable: Scrollable::new(column),
+            key: PhantomData,
+        }
+    }
+
+    /// Sets the [`Id`] of the inner [`Scrollable`], e.g. to scroll it with operations.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.scrollable = self.scrollable.id(id);
+        self
+    }
+
+    /// Sets the width of the [`ReorderableScrollable`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.scrollable = self.scrollable.width(width);
+        self
+    }
+
+    /// Sets the height of the [`ReorderableScrollable`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.scrollable = self.scrollable.height(height);
+        self
+    }
+
+    /// Returns the [`Viewport`] of the inner [`Scrollable`], if one of the children of the
+    /// [`Column`] is being dragged.
+    fn dragging_viewport(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<Viewport> {
+        let probe = self.probe(tree, layout, renderer);
+
+        probe.viewport.filter(|_| probe.dragging)
+    }
+
+    /// Runs a [`Probe`] on the inner [`Scrollable`].
+    fn probe(&self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer) -> Probe<Key> {
+        let mut probe = Probe::<Key> {
+            viewport: None,
+            grabbed: false,
+            dragging: false,
+            target: None,
+            key: PhantomData,
+        };
+        self.scrollable.operate(tree, layout, renderer, &mut probe);
+
+        probe
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ReorderableScrollable<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: Clone + 'a,
+    Theme: Catalog + scrollable::Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.scrollable as &dyn Widget<_, _, _>)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.scrollable as &dyn Widget<_, _, _>]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.scrollable.size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.scrollable.size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = self
+            .scrollable
+            .layout(&mut tree.children[0], renderer, limits);
+
+        layout::Node::with_children(node.size(), vec![node])
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let content_layout = layout.children().next().expect("the scrollable layout");
+        self.scrollable.update(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                | Event::Keyboard(keyboard::Event::KeyPressed { .. })
+        );
+        // Only run the probe over the content once a press may have started a drag
+        let probe = (is_press || state.grabbed)
+            .then(|| self.probe(&mut tree.children[0], content_layout, renderer));
+        if let Some(probe) = &probe {
+            state.grabbed = probe.grabbed;
+            state.dragging = probe.dragging;
+        }
+        let dragging = state.dragging;
+
+        let mut is_near_edge = false;
+        let is_scrolled = match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                // Start scrolling on the next frame when dragging near the edges
+                is_near_edge = dragging
+                    && cursor.position().is_some_and(|position| {
+                        flex::is_near_edge(&Axis::Vertical, layout.bounds(), position)
+                    });
+                false
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { .. }) => dragging,
+            Event::Keyboard(keyboard::Event::KeyPressed { .. }) if dragging => {
+                // Keep the drop location moved with the keyboard in view, without locating
+                // the drop again under the cursor
+                let scroll = probe
+                    .and_then(|probe| probe.viewport.zip(probe.target))
+                    .and_then(|(viewport, target)| {
+                        Some(ScrollViewport {
+                            bounds: viewport.bounds,
+                            offset: viewport.offset_to_reveal(target)?,
+                        })
+                    });
+                if let Some(mut scroll) = scroll {
+                    self.scrollable.operate(
+                        &mut tree.children[0],
+                        content_layout,
+                        renderer,
+                        &mut scroll,
+                    );
+                    shell.request_redraw();
+                }
+                false
+            }
+            Event::Window(window::Event::RedrawRequested(_)) if dragging => {
+                let bounds = layout.bounds();
+                let offset = cursor.position().and_then(|position| {
+                    let viewport =
+                        self.dragging_viewport(&mut tree.children[0], content_layout, renderer)?;
+                    let delta = flex::auto_scroll_delta(
+                        &Axis::Vertical,
+                        viewport.content_bounds - viewport.translation,
+                        &bounds,
+                        position,
+                    )?;
+
+                    Some((viewport, viewport.scrolled_by(delta)?))
+                });
+                if let Some((viewport, offset)) = offset {
+                    self.scrollable.operate(
+                        &mut tree.children[0],
+                        content_layout,
+                        renderer,
+                        &mut ScrollViewport {
+                            bounds: viewport.bounds,
+                            offset,
+                        },
+                    );
+                }
+                offset.is_some()
+            }
+            _ => false,
+        };
+
+        if is_near_edge {
+            shell.request_redraw();
+        }
+        if is_scrolled {
+            // The content scrolled under the cursor, so let the column locate the drop again
+            if let Some(position) = cursor.position() {
+                self.scrollable.update(
+                    &mut tree.children[0],
+                    &Event::Mouse(mouse::Event::CursorMoved { position }),
+                    content_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+            // Keep scrolling on the next frames
+            shell.request_redraw();
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let content_layout = layout.children().next().expect("the scrollable layout");
+        self.scrollable
+            .operate(&mut tree.children[0], content_layout, renderer, operation);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let content_layout = layout.children().next().expect("the scrollable layout");
+        self.scrollable.mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let content_layout = layout.children().next().expect("the scrollable layout");
+        self.scrollable.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let content_layout = layout.children().next().expect("the scrollable layout");
+        self.scrollable
+            .overlay(&mut tree.children[0], content_layout, renderer, translation)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<ReorderableScrollable<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: Clone + 'a,
+    Theme: Catalog + scrollable::Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: ReorderableScrollable<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct State {
+    /// Whether one of the children of the [`Column`] is grabbed or being dragged.
+    grabbed: bool,
+    /// Whether one of the children of the [`Column`] is being dragged.
+    dragging: bool,
+}
+
+/// An [`Operation`] finding the [`Viewport`] of the inner [`Scrollable`], whether one of
+/// the children of the [`Column`] is grabbed or being dragged, and the bounds of its drop
+/// location.
+struct Probe<Key> {
+    viewport: Option<Viewport>,
+    grabbed: bool,
+    dragging: bool,
+    target: Option<Rectangle>,
+    key: PhantomData<fn() -> Key>,
+}
+
+impl<Key> Operation for Probe<Key>
+where
+    Key: Copy + PartialEq + 'static,
+{
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn ScrollableState,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        // The inner scrollable is the outermost one visited
+        if self.viewport.is_none() {
+            self.viewport = Some(Viewport {
+                bounds,
+                content_bounds,
+                translation,
+            });
+        }
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, _id: Option<&Id>) {
+        if let Some(items) = state.downcast_mut::<Items<Key>>() {
+            self.grabbed |= items.drag.is_some();
+            self.dragging |= items.dragging;
+            if let Some(DragInfo {
+                key,
+                drop_location: Some(drop_location),
+            }) = items.drag
+            {
+                self.target = self.target.or_else(|| {
+                    let (_, dragged) =
+                        items.bounds.iter().find(|(item_key, _)| *item_key == key)?;
+
+                    Some(flex::slot_bounds(
+                        &Axis::Vertical,
+                        items.bounds.iter().map(|(_, bounds)| *bounds),
+                        *dragged,
+                        drop_location,
+                    ))
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Build the event sequences of drag gestures, for testing applications using the
+//! reorderable widgets.
+//!
+//! The sequences can be fed to a headless test harness, like the simulator of `iced_test`,
+//! to perform a full drag gesture in one call. Combine them with [`Column::id`] and the
+//! [`geometry`](crate::operation::geometry) operation to find the positions of the child
+//! elements to drag.
+//!
+//! The [`Column::mouse_hold_delay`], double clicks and animations are measured with the
+//! system clock by default. Pass a [`Clock`] to [`Column::clock`] and advance it between
+//! the events to test them with simulated timestamps. With [`Column::frame_sync`] enabled,
+//! the drag is only processed when the harness redraws, e.g. on a [`redraw`] event.
+//!
+//! [`Column::id`]: crate::Column::id
+//! [`Column::mouse_hold_delay`]: crate::Column::mouse_hold_delay
+//! [`Column::clock`]: crate::Column::clock
+//! [`Column::frame_sync`]: crate::Column::frame_sync
+use iced::mouse;
+use iced::time::{Duration, Instant};
+use iced::touch;
+use iced::window;
+use iced::{Event, Point};
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// The number of intermediate moves of a simulated drag gesture.
+const STEPS: usize = 10;
+
+/// Returns the events of a mouse drag gesture from `from` to `to`.
+///
+/// The cursor is moved to `from`, the left button is pressed, the cursor is moved to `to`
+/// in several steps, crossing any drag threshold on the way, and the button is released.
+///
+/// # Example
+/// ```
+/// use iced::Point;
+/// use iced_reorderable::simulate;
+///
+/// let events = simulate::drag(Point::new(10.0, 10.0), Point::new(10.0, 90.0));
+/// assert!(events.len() > 3);
+/// ```
+pub fn drag(from: Point, to: Point) -> Vec<Event> {
+    let moved = |position| Event::Mouse(mouse::Event::CursorMoved { position });
+
+    std::iter::once(moved(from))
+        .chain([Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        ))])
+        .chain(steps(from, to).map(moved))
+        .chain([Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        ))])
+        .collect()
+}
+
+/// Returns the events of a touch drag gesture from `from` to `to` with a single finger.
+///
+/// The finger is pressed at `from`, moved to `to` in several steps, crossing any drag
+/// threshold on the way, and lifted.
+pub fn touch_drag(from: Point, to: Point) -> Vec<Event> {
+    let id = touch::Finger(0);
+
+    std::iter::once(Event::Touch(touch::Event::FingerPressed {
+        id,
+        position: from,
+    }))
+    .chain(steps(from, to).map(|position| Event::Touch(touch::Event::FingerMoved { id, position })))
+    .chain([Event::Touch(touch::Event::FingerLifted {
+        id,
+        position: to,
+    })])
+    .collect()
+}
+
+/// Returns the intermediate positions of a simulated drag gesture, ending at `to`.
+fn steps(from: Point, to: Point) -> impl Iterator<Item = Point> {
+    (1..=STEPS).map(move |step| {
+        let t = step as f32 / STEPS as f32;
+
+        Point::new(from.x + (to.x - from.x) * t, from.y + (to.y - from.y) * t)
+    })
+}
+
+/// Returns the event of a redraw at the given time.
+///
+/// Redraws advance the animations and process the moves coalesced by
+/// [`Column::frame_sync`](crate::Column::frame_sync).
+pub fn redraw(now: Instant) -> Event {
+    Event::Window(window::Event::RedrawRequested(now))
+}
+
+/// A simulated clock, advanced manually.
+///
+/// Clones share the same time, so a clone can be advanced by a test while the widget
+/// reads it through [`Clock::source`].
+///
+/// # Example
+/// ```
+/// use iced::time::Duration;
+/// use iced_reorderable::simulate::Clock;
+///
+/// let clock = Clock::new();
+/// let start = clock.now();
+///
+/// clock.advance(Duration::from_millis(500));
+/// assert_eq!(clock.now() - start, Duration::from_millis(500));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Clock {
+    now: Rc<Cell<Instant>>,
+}
+
+impl Clock {
+    /// Creates a new [`Clock`] starting at the current system time.
+    pub fn new() -> Self {
+        Self {
+            now: Rc::new(Cell::new(Instant::now())),
+        }
+    }
+
+    /// Returns the current time of the [`Clock`].
+    pub fn now(&self) -> Instant {
+        self.now.get()
+    }
+
+    /// Moves the [`Clock`] forward by the given duration.
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+
+    /// Returns a function reading the [`Clock`], to be passed to
+    /// [`Column::clock`](crate::Column::clock) or [`Row::clock`](crate::Row::clock).
+    pub fn source(&self) -> impl Fn() -> Instant + 'static {
+        let now = Rc::clone(&self.now);
+
+        move || now.get()
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
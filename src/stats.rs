@@ -0,0 +1,22 @@
+//! Measure the memory used by the reorderable widgets.
+//!
+//! Only available with the `memory-stats` feature.
+use std::mem;
+
+/// The number of elements and the allocated capacity of the buffers of a widget or of a
+/// [`Keys`](crate::Keys) interner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryStats {
+    /// The number of elements stored, e.g. child elements or interned identifiers.
+    pub len: usize,
+    /// The number of elements that can be stored without reallocating.
+    pub capacity: usize,
+    /// The approximate number of bytes allocated on the heap by the buffers, excluding
+    /// the heap data of the elements themselves, e.g. of the child widgets.
+    pub heap_bytes: usize,
+}
+
+/// Returns the number of bytes allocated by the given vector.
+pub(crate) fn heap_bytes<T>(vec: &Vec<T>) -> usize {
+    vec.capacity() * mem::size_of::<T>()
+}
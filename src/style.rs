@@ -0,0 +1,58 @@
+//! Presets for the appearance of the reorderable widgets.
+//!
+//! Each preset is a styling function producing a [`Style`], usable in one line:
+//!
+//! ```no_run
+//! use iced::widget::text;
+//! use iced_reorderable::{style, Column};
+//!
+//! # #[derive(Debug, Clone)]
+//! # enum Message {}
+//! let column: Column<'_, usize, Message> = Column::new()
+//!     .push(0, text("First"))
+//!     .push(1, text("Second"))
+//!     .style(style::dashed);
+//! ```
+use crate::{Status, Style, TargetStyle};
+
+use iced::Theme;
+
+pub use crate::default;
+
+/// A subtle [`Style`], with a weak primary marker color.
+pub fn subtle(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let color = match status {
+        Status::Invalid => palette.danger.weak.color,
+        Status::Idle | Status::Grabbed | Status::Dragging => palette.primary.weak.color,
+    };
+
+    Style {
+        color,
+        target: TargetStyle::colored(color),
+        ..Style::DEFAULT
+    }
+}
+
+/// A bold [`Style`], with a strong primary marker color.
+pub fn bold(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let color = match status {
+        Status::Invalid => palette.danger.strong.color,
+        Status::Idle | Status::Grabbed | Status::Dragging => palette.primary.strong.color,
+    };
+
+    Style {
+        color,
+        target: TargetStyle::colored(color),
+        ..Style::DEFAULT
+    }
+}
+
+/// A [`Style`] with a dashed marker line of the primary color.
+pub fn dashed(theme: &Theme, status: Status) -> Style {
+    Style {
+        dashed: true,
+        ..default(theme, status)
+    }
+}
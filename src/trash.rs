@@ -0,0 +1,368 @@
+//! Delete child elements by dropping them on a trash zone.
+use crate::group::DragGroup;
+use crate::payload::Payload;
+use crate::{Catalog, Status, Style, StyleFn};
+
+use iced::advanced::layout;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::mouse;
+use iced::touch;
+use iced::Event;
+use iced::{Border, Element, Length, Rectangle, Size, Vector};
+
+/// An element wrapping content that deletes the child elements dropped on it.
+///
+/// The [`TrashZone`] joins the [`DragGroup`] of the [`Column`](crate::Column)s whose child
+/// elements can be deleted. It highlights while a child element of the group is dragged,
+/// more strongly when the child element is over it, and produces the message set with
+/// [`TrashZone::on_delete`] when the child element is dropped on it. The source column
+/// produces no drop or cancel message for such a drop.
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::{column, text};
+/// use iced_reorderable::{Column, DragGroup, TrashZone};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(u64, usize),
+///     Deleted(u64),
+/// }
+///
+/// fn view<'a>(group: &DragGroup<u64>, items: &'a [(u64, String)]) -> iced::Element<'a, Message> {
+///     let list = Column::with_children(
+///         items
+///             .iter()
+///             .map(|(id, item)| (*id, text(item.as_str()).into())),
+///     )
+///     .drag_group(group)
+///     .on_drop(Message::Dropped);
+///
+///     let trash = TrashZone::new(text("Drop here to delete"), group).on_delete(Message::Deleted);
+///
+///     column![list, trash].spacing(20).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TrashZone<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    group: DragGroup<Key>,
+    class: Theme::Class<'a>,
+    on_delete: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_delete_payload: Option<Box<dyn Fn(Payload) -> Message + 'a>>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> TrashZone<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a [`TrashZone`] wrapping the given content, accepting the child elements of
+    /// the given [`DragGroup`].
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        group: &DragGroup<Key>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            group: group.clone(),
+            class: Theme::default(),
+            on_delete: None,
+            on_delete_payload: None,
+        }
+    }
+
+    /// Sets the message that will be produced when a child element is dropped on the
+    /// [`TrashZone`].
+    ///
+    /// The message will be produced with the key of the dropped child element.
+    pub fn on_delete<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_delete = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced instead of the [`TrashZone::on_delete`]
+    /// message when the child element dropped on the [`TrashZone`] carries a [`Payload`].
+    ///
+    /// The message will be produced with the payload set with
+    /// [`Column::payload`](crate::Column::payload) on the source column.
+    pub fn on_delete_payload<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Payload) -> Message + 'a,
+    {
+        self.on_delete_payload = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the style of the [`TrashZone`].
+    ///
+    /// The highlight is drawn with the drop position marker color of the [`Style`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TrashZone`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TrashZone<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key>::default())
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let member = *state.member.get_or_insert_with(|| self.group.join());
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let incoming = self
+                    .group
+                    .drag()
+                    .filter(|drag| drag.source != member && cursor.is_over(layout.bounds()))
+                    .map(|drag| (drag.id, drag.key));
+
+                if incoming != state.incoming {
+                    self.group.hover(member, incoming.is_some());
+                    state.incoming = incoming;
+                }
+                if self.group.drag().is_some() {
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some((id, key)) = state.incoming.take() {
+                    if let Some((on_delete_payload, payload)) = self
+                        .on_delete_payload
+                        .as_deref()
+                        .zip(self.group.payload_of(id))
+                    {
+                        shell.publish(on_delete_payload(payload));
+                        shell.capture_event();
+                    } else if let Some(on_delete) =
+                        self.on_delete.as_deref().filter(|_| self.group.is_live(id))
+                    {
+                        shell.publish(on_delete(key));
+                        shell.capture_event();
+                    }
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+
+        // Forget drags that ended without a drop
+        if state
+            .incoming
+            .is_some_and(|(id, _)| !self.group.is_live(id))
+        {
+            state.incoming = None;
+            shell.request_redraw();
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State<Key>>().incoming.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let highlight = if state.incoming.is_some() {
+            Some(0.3)
+        } else if self.group.drag().is_some() {
+            Some(0.1)
+        } else {
+            None
+        };
+
+        if let Some(alpha) = highlight {
+            let color = theme.style(&self.class, Status::Dragging).color;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border: Border {
+                        color,
+                        width: crate::flex::MARKER_LINE_WIDTH,
+                        radius: 0.0.into(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                color.scale_alpha(alpha),
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<TrashZone<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: TrashZone<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct State<K> {
+    /// The number identifying the [`TrashZone`] in its [`DragGroup`], once it has joined.
+    member: Option<usize>,
+    /// The number of the drag hovering the [`TrashZone`] with the key of the dragged child
+    /// element.
+    incoming: Option<(u64, K)>,
+}
+
+impl<Key> Default for State<Key> {
+    fn default() -> Self {
+        Self {
+            member: None,
+            incoming: None,
+        }
+    }
+}
@@ -0,0 +1,1046 @@
+//! Reorder very large lists by building only the child elements in view.
+use crate::flex::{self, DragState};
+use crate::{Catalog, ItemFocus, Items, Status, Style, StyleFn, Translate};
+
+use iced::advanced::layout;
+use iced::advanced::layout::flex::Axis;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{tree, Id, Operation, Tree, Widget};
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::mouse;
+use iced::time::{Duration, Instant};
+use iced::touch;
+use iced::Event;
+use iced::{Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Size, Vector};
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+/// The view function of a [`VirtualColumn`], building the keyed child elements of a range
+/// of indices.
+type ViewFn<'a, Key, Message, Theme, Renderer> =
+    Box<dyn Fn(Range<usize>) -> Vec<(Key, Element<'a, Message, Theme, Renderer>)> + 'a>;
+
+/// The number of child elements built before the [`VirtualColumn`] knows its viewport.
+const INITIAL_WINDOW: usize = 32;
+
+/// A vertical list of child elements with a fixed height, of which only the ones in view
+/// are built, laid out and drawn, and which allows reordering them by dragging and
+/// dropping.
+///
+/// The [`VirtualColumn`] is meant to be placed in a
+/// [`Scrollable`](iced::widget::Scrollable): it takes the number of items and a view
+/// function building the child elements of a range of indices, and calls it with the
+/// range in view, plus a few child elements of overscan above and below. The dragged
+/// child element is kept built while it is scrolled out of view.
+///
+/// Drop locations use the same indexing as the [`Column`](crate::Column), over all the
+/// items rather than the ones built: the index of the item the dragged child element is
+/// dropped in front of, or the number of items when dropped after the last one.
+///
+/// Like for a [`Column`](crate::Column), the child elements of a [`VirtualColumn`]
+/// producing drop messages take part in the focus operations: the focused child element is
+/// outlined, Space picks it up, the up and down arrow keys move it among all the items and
+/// Enter drops it. Only the built child elements are exposed to the focus operations and
+/// to the ones of the [`operation`](crate::operation) module.
+///
+/// # Example
+/// ```no_run
+/// use iced::widget::{scrollable, text};
+/// use iced_reorderable::VirtualColumn;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dropped(usize, usize),
+/// }
+///
+/// fn view(rows: &[String]) -> iced::Element<'_, Message> {
+///     let list = VirtualColumn::new(rows.len(), 24, |range| {
+///         range
+///             .map(|index| (index, text(rows[index].as_str()).into()))
+///             .collect()
+///     })
+///     .on_drop(Message::Dropped);
+///
+///     scrollable(list).into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct VirtualColumn<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    count: usize,
+    item_height: f32,
+    view: ViewFn<'a, Key, Message, Theme, Renderer>,
+    built: RefCell<Built<'a, Key, Message, Theme, Renderer>>,
+    spacing: f32,
+    padding: Padding,
+    width: Length,
+    overscan: usize,
+    class: Theme::Class<'a>,
+    on_drag: Option<Box<dyn Fn(Key, usize) -> Message + 'a>>,
+    on_drop: Option<Box<dyn Fn(Key, usize) -> Message + 'a>>,
+    on_cancel: Option<Box<dyn Fn(Key) -> Message + 'a>>,
+    on_auto_scroll: Option<Box<dyn Fn(Vector) -> Message + 'a>>,
+    drop_position_marker: bool,
+    drag_follow: bool,
+    id: Option<Id>,
+    mouse_hold_delay: Option<Duration>,
+    clock: flex::TimeSource<'a>,
+    keyboard_wrap: bool,
+}
+
+/// The child elements of a [`VirtualColumn`] built for its latest layout.
+struct Built<'a, Key, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// The indices of the child elements among all the items.
+    indices: Vec<usize>,
+    keys: Vec<Key>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Key, Message, Theme, Renderer> VirtualColumn<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a [`VirtualColumn`] of `count` items with the given height, whose child
+    /// elements are built by the `view` function for a range of indices.
+    ///
+    /// The `view` function must return one keyed child element for each index of the
+    /// range, in order.
+    pub fn new<F>(count: usize, item_height: impl Into<Pixels>, view: F) -> Self
+    where
+        F: Fn(Range<usize>) -> Vec<(Key, Element<'a, Message, Theme, Renderer>)> + 'a,
+    {
+        Self {
+            count,
+            item_height: item_height.into().0,
+            view: Box::new(view),
+            built: RefCell::new(Built {
+                indices: Vec::new(),
+                keys: Vec::new(),
+                children: Vec::new(),
+            }),
+            spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Fill,
+            overscan: 4,
+            class: Theme::default(),
+            on_drag: None,
+            on_drop: None,
+            on_cancel: None,
+            on_auto_scroll: None,
+            drop_position_marker: true,
+            drag_follow: false,
+            id: None,
+            mouse_hold_delay: None,
+            clock: flex::TimeSource::default(),
+            keyboard_wrap: false,
+        }
+    }
+
+    /// Sets the vertical spacing _between_ the items.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`VirtualColumn`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`VirtualColumn`].
+    ///
+    /// By default, the [`VirtualColumn`] fills the available width. Its height is always
+    /// the total height of the items.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the number of child elements built above and below the ones in view, so that
+    /// they are ready to be drawn when scrolling.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Sets the style of the [`VirtualColumn`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`VirtualColumn`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the message that will be produced when dragging starts or the dragged child
+    /// element has been dragged to another position in the [`VirtualColumn`].
+    ///
+    /// The message will be produced with the key of the dragged child element and the index
+    /// of the drag position among all the items.
+    pub fn on_drag<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, usize) -> Message + 'a,
+    {
+        self.on_drag = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when the dragged child element is dropped on
+    /// the [`VirtualColumn`].
+    ///
+    /// The message will be produced with the key of the dragged child element and the index
+    /// of the drop position among all the items.
+    pub fn on_drop<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key, usize) -> Message + 'a,
+    {
+        self.on_drop = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced when the user cancels active dragging by
+    /// right-clicking, by pressing Escape, or when the dragging touch is lost. Active
+    /// dragging is canceled as well when the window loses focus.
+    pub fn on_cancel<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Key) -> Message + 'a,
+    {
+        self.on_cancel = Some(Box::new(message));
+        self
+    }
+
+    /// Sets the message that will be produced on every frame while a child element is
+    /// dragged near the top or bottom edge of the viewport, and when a picked up child
+    /// element is moved out of view with the keyboard.
+    ///
+    /// The message will be produced with the amount to scroll by, like
+    /// [`Column::on_auto_scroll`](crate::Column::on_auto_scroll).
+    pub fn on_auto_scroll<F>(mut self, message: F) -> Self
+    where
+        F: Fn(Vector) -> Message + 'a,
+    {
+        self.on_auto_scroll = Some(Box::new(message));
+        self
+    }
+
+    /// Sets whether a marker line will be shown at the position where the dragged child
+    /// element would be dropped.
+    pub fn drop_position_marker(mut self, drop_position_marker: bool) -> Self {
+        self.drop_position_marker = drop_position_marker;
+        self
+    }
+
+    /// Sets whether a child element should follow the cursor or touch while being dragged.
+    pub fn drag_follow(mut self, drag_follow: bool) -> Self {
+        self.drag_follow = drag_follow;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`VirtualColumn`], to target it with the operations of the
+    /// [`operation`](crate::operation) module, e.g. to focus or grab a built child element.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets how long the mouse button has to be held on a child element before it can be
+    /// dragged, like [`Column::mouse_hold_delay`](crate::Column::mouse_hold_delay).
+    pub fn mouse_hold_delay(mut self, mouse_hold_delay: Option<Duration>) -> Self {
+        self.mouse_hold_delay = mouse_hold_delay;
+        self
+    }
+
+    /// Sets the clock the [`VirtualColumn`] reads the current time from for the
+    /// [`VirtualColumn::mouse_hold_delay`], like [`Column::clock`](crate::Column::clock).
+    pub fn clock(mut self, clock: impl Fn() -> Instant + 'a) -> Self {
+        self.clock = flex::TimeSource::new(clock);
+        self
+    }
+
+    /// Sets whether moving a picked up child element with the arrow keys should wrap
+    /// around the ends of the [`VirtualColumn`], like
+    /// [`Column::keyboard_wrap`](crate::Column::keyboard_wrap).
+    pub fn keyboard_wrap(mut self, keyboard_wrap: bool) -> Self {
+        self.keyboard_wrap = keyboard_wrap;
+        self
+    }
+
+    /// Returns the distance between the tops of two consecutive items.
+    fn stride(&self) -> f32 {
+        self.item_height + self.spacing
+    }
+
+    /// Returns the range of the items to build for the given viewport, including the
+    /// overscan.
+    fn window(&self, bounds: Rectangle, viewport: &Rectangle) -> Range<usize> {
+        let top = bounds.y + self.padding.top;
+        let index_at = |y: f32| ((y - top) / self.stride()).max(0.0) as usize;
+
+        let start = index_at(viewport.y).saturating_sub(self.overscan);
+        let end = index_at(viewport.y + viewport.height) + 1 + self.overscan;
+
+        start.min(self.count)..end.min(self.count)
+    }
+
+    /// Returns the index of the drop location among all the items at the given position.
+    fn drop_location(&self, bounds: Rectangle, position: Point) -> usize {
+        let top = bounds.y + self.padding.top;
+        let slot = ((position.y - top - self.item_height * 0.5) / self.stride()).ceil();
+
+        slot.clamp(0.0, self.count as f32) as usize
+    }
+
+    /// Returns the bounds of the item at the given index among all the items.
+    fn item_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        Rectangle {
+            x: bounds.x + self.padding.left,
+            y: bounds.y + self.padding.top + index as f32 * self.stride(),
+            width: bounds.width - self.padding.horizontal(),
+            height: self.item_height,
+        }
+    }
+
+    /// Returns the bounds of the drop position marker line for the given drop location.
+    fn marker_bounds(&self, bounds: Rectangle, drop_location: usize) -> Rectangle {
+        let y =
+            bounds.y + self.padding.top + drop_location as f32 * self.stride() - self.spacing * 0.5;
+
+        Rectangle {
+            x: bounds.x + self.padding.left,
+            y: y - flex::MARKER_LINE_WIDTH * 0.5,
+            width: bounds.width - self.padding.horizontal(),
+            height: flex::MARKER_LINE_WIDTH,
+        }
+    }
+
+    /// Returns the key of the child element following the cursor and its translation
+    /// from its laid out position, if any.
+    fn dragged_translation(
+        &self,
+        state: &State<Key>,
+        built: &Built<'a, Key, Message, Theme, Renderer>,
+        layout: Layout<'_>,
+    ) -> Option<(Key, Vector)> {
+        state
+            .drag
+            .follow_translation(built.keys.iter().zip(layout.children()))
+            .filter(|_| self.drag_follow)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> flex::Gesture<Key, Message>
+    for VirtualColumn<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    type State = State<Key>;
+
+    fn drag<'s>(&self, state: &'s mut State<Key>) -> &'s mut DragState<Key> {
+        &mut state.drag
+    }
+
+    fn grab_at(
+        &mut self,
+        _state: &mut State<Key>,
+        _shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        _event: &Event,
+        position: Point,
+    ) -> Option<(Key, Rectangle)> {
+        flex::child_at(&self.built.get_mut().keys, layout, position)
+    }
+
+    fn drop_location(&self, layout: Layout<'_>, _key: Key, position: Point) -> usize {
+        self.drop_location(layout.bounds(), position)
+    }
+
+    fn drag_message(&self, key: Key, drop_location: usize) -> Option<Message> {
+        self.on_drag
+            .as_ref()
+            .map(|on_drag| on_drag(key, drop_location))
+    }
+
+    fn drop_message(&self, key: Key, drop_location: usize) -> Option<Message> {
+        self.on_drop
+            .as_ref()
+            .map(|on_drop| on_drop(key, drop_location))
+    }
+
+    fn cancel_message(&self, key: Key) -> Option<Message> {
+        self.on_cancel.as_ref().map(|on_cancel| on_cancel(key))
+    }
+
+    fn hold(&self) -> Option<flex::Hold> {
+        self.clock.hold(self.mouse_hold_delay)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> flex::PickUp<Key, Message>
+    for VirtualColumn<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn picked_up<'s>(&self, state: &'s mut State<Key>) -> &'s mut bool {
+        &mut state.picked_up
+    }
+
+    fn focused(&self, state: &State<Key>) -> Option<Key> {
+        state.focused
+    }
+
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn keyboard_wrap(&self) -> bool {
+        self.keyboard_wrap
+    }
+
+    fn pick_up(
+        &self,
+        state: &mut State<Key>,
+        _shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        key: Key,
+    ) -> bool {
+        let built = self.built.borrow();
+        flex::pick_up(&mut state.drag, &layout, &built.keys, key, |position| {
+            self.drop_location(layout.bounds(), position)
+        })
+    }
+
+    fn slot_position(
+        &self,
+        _axis: &Axis,
+        layout: Layout<'_>,
+        slot: usize,
+        position: Point,
+    ) -> Point {
+        // The slots are computed from the indices, as most items are not laid out
+        Point::new(position.x, self.item_bounds(layout.bounds(), slot).y)
+    }
+
+    fn reveal(
+        &self,
+        state: &mut State<Key>,
+        shell: &mut Shell<'_, Message>,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) {
+        let (Some(on_auto_scroll), Some(drop_location)) =
+            (self.on_auto_scroll.as_deref(), state.drag.drop_location())
+        else {
+            return;
+        };
+        let target = self.item_bounds(layout.bounds(), drop_location);
+        if let Some(delta) = flex::reveal_delta(&Axis::Vertical, target, viewport) {
+            shell.publish(on_auto_scroll(delta));
+        }
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for VirtualColumn<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key> {
+            range: 0..INITIAL_WINDOW.min(self.count),
+            ..State::default()
+        })
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        // The child elements are only built when laying out
+        Vec::new()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        if state.dragged.is_some_and(|index| index >= self.count) {
+            state.drag = DragState::Idle;
+            state.dragged = None;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let range = state.range.start.min(self.count)..state.range.end.min(self.count);
+        let dragged = state
+            .dragged
+            .filter(|index| !range.contains(index) && *index < self.count);
+
+        let mut built = self.built.borrow_mut();
+        let (indices, (keys, children)): (Vec<_>, (Vec<_>, Vec<_>)) = range
+            .clone()
+            .zip((self.view)(range))
+            .chain(
+                dragged
+                    .into_iter()
+                    .flat_map(|index| std::iter::repeat(index).zip((self.view)(index..index + 1))),
+            )
+            .unzip();
+        *built = Built {
+            indices,
+            keys,
+            children,
+        };
+
+        // Reconcile the child trees by key, so that the state of each child element
+        // follows its key when scrolling or reordering.
+        if state.keys != built.keys {
+            let mut previous: Vec<_> = state
+                .keys
+                .iter()
+                .copied()
+                .zip(tree.children.drain(..).map(Some))
+                .collect();
+            tree.children = built
+                .keys
+                .iter()
+                .map(|key| {
+                    previous
+                        .iter_mut()
+                        .find(|(previous_key, child)| previous_key == key && child.is_some())
+                        .and_then(|(_, child)| child.take())
+                        .unwrap_or_else(Tree::empty)
+                })
+                .collect();
+            state.keys = built.keys.clone();
+        }
+        tree.diff_children(&built.children);
+
+        let limits = limits.width(self.width).shrink(self.padding);
+        let cross_space = limits.max().width;
+        let child_limits = layout::Limits::new(
+            Size::new(0.0, self.item_height),
+            Size::new(cross_space, self.item_height),
+        );
+
+        let mut content_width: f32 = 0.0;
+        let nodes = built
+            .children
+            .iter()
+            .zip(&built.indices)
+            .zip(&mut tree.children)
+            .map(|((child, index), child_tree)| {
+                let node = child
+                    .as_widget()
+                    .layout(child_tree, renderer, &child_limits);
+                content_width = content_width.max(node.size().width);
+
+                node.move_to(Point::new(
+                    self.padding.left,
+                    self.padding.top + *index as f32 * self.stride(),
+                ))
+            })
+            .collect();
+
+        let content_height = if self.count == 0 {
+            0.0
+        } else {
+            self.count as f32 * self.stride() - self.spacing
+        };
+        let size = limits
+            .resolve(
+                self.width,
+                Length::Shrink,
+                Size::new(content_width, content_height),
+            )
+            .expand(self.padding);
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let built = self.built.get_mut();
+        let drag_state = tree.state.downcast_ref::<State<Key>>().drag;
+        if let Some((event, cursor)) =
+            flex::propagate_event_to_children(&drag_state, 0.0, event, cursor)
+        {
+            for ((child, state), item_layout) in built
+                .children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                child.as_widget_mut().update(
+                    state,
+                    event,
+                    item_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) && state.focused.take().is_some()
+        {
+            shell.request_redraw();
+        }
+        flex::update_picked_up(
+            &Axis::Vertical,
+            self,
+            state,
+            event,
+            layout,
+            cursor,
+            shell,
+            viewport,
+        );
+        flex::update(self, state, event, layout, cursor, shell);
+        if state.drag.is_idle() {
+            state.picked_up = false;
+        }
+        if let Some(on_auto_scroll) = self.on_auto_scroll.as_deref() {
+            flex::auto_scroll(
+                &Axis::Vertical,
+                self,
+                state,
+                event,
+                layout,
+                cursor,
+                shell,
+                viewport,
+                on_auto_scroll,
+            );
+        }
+
+        let built = self.built.borrow();
+        let bounds = layout.bounds();
+
+        // Keep the dragged child element built while it is scrolled out of view
+        state.dragged = state.drag.key().and_then(|key| {
+            built
+                .keys
+                .iter()
+                .position(|built_key| *built_key == key)
+                .map(|position| built.indices[position])
+        });
+
+        let window = self.window(bounds, viewport);
+        if window != state.range {
+            state.range = window;
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let built = self.built.borrow();
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let mut items = Items {
+            column: layout.bounds(),
+            bounds: built
+                .keys
+                .iter()
+                .copied()
+                .zip(layout.children().map(|item_layout| item_layout.bounds()))
+                .collect(),
+            grab: None,
+            focus: None,
+            dragging: matches!(state.drag, DragState::Dragged { .. }),
+            drag: state.drag.info(),
+        };
+        operation.custom(&mut items, self.id.as_ref());
+        if let Some(key) = items.grab {
+            state.picked_up =
+                flex::pick_up(&mut state.drag, &layout, &built.keys, key, |position| {
+                    self.drop_location(layout.bounds(), position)
+                });
+        }
+        if let Some(key) = items.focus.filter(|key| built.keys.contains(key)) {
+            state.focused = Some(key);
+        }
+
+        let dragged = self.dragged_translation(state, &built, layout);
+        let is_focusable = self.on_drop.is_some();
+        let column_state = tree.state.downcast_mut::<State<Key>>();
+
+        operation.container(self.id.as_ref(), layout.bounds(), &mut |operation| {
+            built
+                .children
+                .iter()
+                .zip(&built.keys)
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|(((child, key), state), item_layout)| {
+                    // Expose the child elements to the focus operations
+                    if is_focusable {
+                        operation.focusable(
+                            &mut ItemFocus {
+                                key: *key,
+                                focused: &mut column_state.focused,
+                            },
+                            None,
+                        );
+                    }
+                    match dragged {
+                        Some((dragged_key, translation)) if dragged_key == *key => {
+                            child.as_widget().operate(
+                                state,
+                                item_layout,
+                                renderer,
+                                &mut Translate {
+                                    operation,
+                                    translation,
+                                },
+                            );
+                        }
+                        _ => {
+                            child
+                                .as_widget()
+                                .operate(state, item_layout, renderer, operation);
+                        }
+                    }
+                });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let drag_state = tree.state.downcast_ref::<State<Key>>().drag;
+        if !drag_state.is_idle() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.built
+            .borrow()
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), item_layout)| {
+                let child_interaction = child.as_widget().mouse_interaction(
+                    state,
+                    item_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                );
+                if self.on_drop.is_some() && cursor.is_over(item_layout.bounds()) {
+                    mouse::Interaction::Pointer.max(child_interaction)
+                } else {
+                    child_interaction
+                }
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(clipped_viewport) = layout.bounds().intersection(viewport) else {
+            return;
+        };
+        let built = self.built.borrow();
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let (dragged_key, dragged_translation) =
+            match self.dragged_translation(state, &built, layout) {
+                Some((key, translation)) => (Some(key), translation),
+                None => (None, Vector::ZERO),
+            };
+
+        let marker = match state.drag {
+            DragState::Dragged { drop_location, .. } if self.drop_position_marker => {
+                Some(self.marker_bounds(layout.bounds(), drop_location))
+            }
+            _ => None,
+        };
+
+        let focused_layout = state
+            .focused
+            .filter(|key| state.drag.key() != Some(*key))
+            .and_then(|key| built.keys.iter().position(|item_key| *item_key == key))
+            .and_then(|index| layout.children().nth(index));
+
+        let mut dragged = None;
+        for (((child, key), state), item_layout) in built
+            .children
+            .iter()
+            .zip(&built.keys)
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            if Some(*key) == dragged_key {
+                dragged = Some((child, state, item_layout));
+                continue;
+            }
+            if item_layout.bounds().intersects(viewport) {
+                child.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    item_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+
+        if let Some(item_layout) = focused_layout {
+            let color = theme.style(&self.class, state.drag.status()).color;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: item_layout.bounds(),
+                    border: Border {
+                        color,
+                        width: flex::MARKER_LINE_WIDTH,
+                        radius: 2.0.into(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        if marker.is_some() || dragged.is_some() {
+            renderer.with_layer(clipped_viewport, |renderer| {
+                if let Some(marker) = marker {
+                    let marker_style = theme.style(&self.class, state.drag.status());
+                    flex::draw_marker(renderer, &Axis::Vertical, marker, viewport, marker_style);
+                }
+                if let Some((child, state, item_layout)) = dragged {
+                    renderer.with_translation(dragged_translation, |renderer| {
+                        child.as_widget().draw(
+                            state,
+                            renderer,
+                            theme,
+                            style,
+                            item_layout,
+                            cursor,
+                            viewport,
+                        );
+                    });
+                }
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .built
+            .get_mut()
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((child, state), item_layout)| {
+                child
+                    .as_widget_mut()
+                    .overlay(state, item_layout, renderer, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<VirtualColumn<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(widget: VirtualColumn<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(widget)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct State<K>
+where
+    K: Copy + PartialEq,
+{
+    drag: DragState<K>,
+    /// Whether the dragged child element has been picked up with the keyboard or an
+    /// operation, without holding a button.
+    picked_up: bool,
+    /// The key of the child element focused by a focus operation, e.g. with Tab.
+    focused: Option<K>,
+    /// The range of the items to build, in view or in the overscan.
+    range: Range<usize>,
+    /// The index of the dragged child element among all the items.
+    dragged: Option<usize>,
+    /// The keys of the child elements the child trees were last reconciled with.
+    keys: Vec<K>,
+}
+
+impl<Key> Default for State<Key>
+where
+    Key: Copy + PartialEq,
+{
+    fn default() -> Self {
+        Self {
+            drag: DragState::Idle,
+            picked_up: false,
+            focused: None,
+            range: 0..0,
+            dragged: None,
+            keys: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a [`VirtualColumn`] of `count` items of 20 spaced by 10 and padded by 5,
+    /// building one child element of overscan.
+    fn column(count: usize) -> VirtualColumn<'static, usize, (), iced::Theme, iced::Renderer> {
+        VirtualColumn::new(count, 20, |_| Vec::new())
+            .spacing(10)
+            .padding(5)
+            .overscan(1)
+    }
+
+    /// The bounds of the test columns, taller than all their items.
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 10_000.0,
+    };
+
+    #[test]
+    fn window_covers_the_viewport_and_the_overscan() {
+        let window = |count, y| {
+            column(count).window(
+                BOUNDS,
+                &Rectangle {
+                    y,
+                    height: 60.0,
+                    ..BOUNDS
+                },
+            )
+        };
+
+        assert_eq!(window(100, 0.0), 0..3);
+        // The items at 95 to 155 are in view
+        assert_eq!(window(100, 100.0), 2..7);
+        // Past the end of the items
+        assert_eq!(window(6, 150.0), 3..6);
+        // Above the items, only the overscan below the viewport is built
+        assert_eq!(window(100, -100.0), 0..2);
+        assert_eq!(window(0, 0.0), 0..0);
+    }
+
+    #[test]
+    fn drop_location_among_all_the_items() {
+        let column = column(100);
+        let drop_location = |y| column.drop_location(BOUNDS, Point::new(50.0, y));
+
+        assert_eq!(drop_location(0.0), 0);
+        assert_eq!(drop_location(16.0), 1);
+        // In the spacing between the first and the second item
+        assert_eq!(drop_location(30.0), 1);
+        assert_eq!(drop_location(50.0), 2);
+        // Far below the items, past any built child element
+        assert_eq!(drop_location(2_995.0), 100);
+        assert_eq!(drop_location(10_000.0), 100);
+    }
+
+    #[test]
+    fn drop_location_at_the_slot_positions() {
+        let column = column(100);
+
+        for slot in [0, 1, 50, 100] {
+            let position = Point::new(50.0, column.item_bounds(BOUNDS, slot).y);
+
+            assert_eq!(column.drop_location(BOUNDS, position), slot);
+        }
+    }
+}